@@ -8,6 +8,21 @@ pub(crate) struct Field {
     pub append: bool,
     #[darling(default)]
     pub ignore: bool,
+    pub rename: Option<String>,
+}
+
+impl Field {
+    fn name(&self, name: &Option<syn::Ident>) -> String {
+        if let Some(name) = &self.rename {
+            return name.clone();
+        }
+
+        name.as_ref()
+            .unwrap()
+            .to_string()
+            .to_uppercase()
+            .replace('_', "-")
+    }
 }
 
 pub(crate) fn impl_macro(ast: &syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
@@ -29,60 +44,98 @@ pub(crate) fn impl_macro(ast: &syn::DeriveInput) -> syn::Result<proc_macro2::Tok
     let mut new_body = Vec::new();
     let mut from_body = Vec::new();
     let mut ser_body = Vec::new();
+    let mut ser_components = Vec::new();
 
     for field in fields {
         let name = &field.ident;
         let ty = &field.ty;
         let field_params = Field::from_field(field)?;
 
-        let field_name = name
-            .as_ref()
-            .unwrap()
-            .to_string()
-            .to_uppercase()
-            .replace('_', "-");
+        let field_name = field_params.name(name);
 
-        let ser_part = if crate::is_vec(ty) {
+        let ser_part = if crate::is_map(ty) {
+            quote::quote! {
+                for v in self.#name.values() {
+                    crate::ser::write_field(writer, &v.key, v)?;
+                }
+            }
+        } else if crate::is_vec(ty) && field_params.append {
             quote::quote! {
                 if self.#name.len() == 1 {
-                    s.push_str(&crate::ser::field(#field_name, &self.#name[0])?);
+                    crate::ser::write_field(writer, #field_name, &self.#name[0])?;
                 }
-                else if self.#name.attr().is_none() {
-                    s.push_str(&crate::ser::field(#field_name, &self.#name)?);
+                else if self.#name.iter().all(|x| x.attr() == self.#name.attr()) {
+                    crate::ser::write_field(writer, #field_name, &self.#name)?;
                 } else {
                     for v in &self.#name {
-                        s.push_str(&crate::ser::field(#field_name, v)?);
+                        crate::ser::write_field(writer, #field_name, v)?;
                     }
                 }
             }
+        } else if crate::is_vec(ty) {
+            // one property by value
+            quote::quote! {
+                for v in &self.#name {
+                    crate::ser::write_field(writer, #field_name, v)?;
+                }
+            }
         } else {
             quote::quote! {
-                s.push_str(&crate::ser::field(#field_name, &self.#name)?);
+                crate::ser::write_field(writer, #field_name, &self.#name)?;
             }
         };
 
-        ser_body.push(ser_part);
+        // properties first, then sub-components
+        ser_body.push(quote::quote! {
+            if <#ty as crate::ser::Serialize>::component().is_none() {
+                #ser_part
+            }
+        });
+        ser_components.push(quote::quote! {
+            if <#ty as crate::ser::Serialize>::component().is_some() {
+                #ser_part
+            }
+        });
 
         if field_params.ignore {
             continue;
         }
 
-        let parser_fn = quote::quote! { crate::parser::#name(content_line)? };
-        let parser = if crate::is_option(ty) {
-            quote::quote! { component.#name = Some(#parser_fn) }
+        let assign = if crate::is_option(ty) {
+            Some(quote::quote! { component.#name = Some(value) })
         } else if crate::is_vec(ty) {
             if field_params.append {
-                quote::quote! { component.#name.append(&mut #parser_fn) }
+                Some(quote::quote! { component.#name.append(&mut value) })
             } else {
-                quote::quote! { component.#name.push(#parser_fn) }
+                Some(quote::quote! { component.#name.push(value) })
+            }
+        } else {
+            None
+        };
+
+        let parser = if let Some(assign) = assign {
+            quote::quote! {
+                match crate::parser::#name(content_line.clone()) {
+                    #[allow(unused_mut)]
+                    Ok(mut value) => #assign,
+                    Err(err) => on_error(content_line, err)?,
+                }
             }
         } else {
             let new_part = quote::quote! {
-                #name: crate::parser::#name(
-                    properties.iter().filter(|x| x.key == #field_name).last()
-                    .ok_or_else(|| crate::Error::Parser(concat!("Missing field ", #field_name).to_string()))?
-                    .clone()
-                )?
+                #name: {
+                    let content_line = properties.iter().rfind(|x| x.key == #field_name)
+                        .ok_or_else(|| crate::Error::Missing(#field_name.to_string()))?
+                        .clone();
+
+                    match crate::parser::#name(content_line.clone()) {
+                        Ok(value) => value,
+                        Err(err) => {
+                            on_error(content_line, err)?;
+                            return Err(crate::Error::Invalid(#field_name.to_string()));
+                        }
+                    }
+                }
             };
 
             new_body.push(new_part);
@@ -103,6 +156,21 @@ pub(crate) fn impl_macro(ast: &syn::DeriveInput) -> syn::Result<proc_macro2::Tok
             type Error = crate::Error;
 
             fn try_from(properties: Vec<crate::ContentLine>) -> crate::Result<Self> {
+                Self::from_content_lines(properties, &mut |_, err| Err(err))
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics #name #ty_generics #where_clause {
+            /**
+             * Builds the component from its properties, `on_error` is called
+             * with the invalid properties and stops the parsing if it
+             * returns an error. An invalid required property always fails.
+             */
+            pub(crate) fn from_content_lines(
+                properties: Vec<crate::ContentLine>,
+                on_error: &mut dyn FnMut(crate::ContentLine, crate::Error) -> crate::Result,
+            ) -> crate::Result<Self> {
                 let mut component = Self {
                     #(#new_body, )*
                     .. Default::default()
@@ -123,6 +191,17 @@ pub(crate) fn impl_macro(ast: &syn::DeriveInput) -> syn::Result<proc_macro2::Tok
 
                 Ok(component)
             }
+
+            /**
+             * Adds a component unknown to this crate.
+             */
+            pub(crate) fn push_raw(&mut self, component: crate::RawComponent) {
+                if component.name.starts_with("X-") {
+                    self.x_comp.push(component);
+                } else {
+                    self.iana_comp.push(component);
+                }
+            }
         }
 
         #[automatically_derived]
@@ -148,8 +227,8 @@ pub(crate) fn impl_macro(ast: &syn::DeriveInput) -> syn::Result<proc_macro2::Tok
             type Err = crate::Error;
 
             fn from_str(s: &str) -> Result<Self, Self::Err> {
-                crate::parser::#parser(&s.replace("\r\n ", ""))
-                    .map_err(crate::Error::from)
+                crate::parser::#parser(&crate::parser::unfold(s))
+                    .map_err(|err| crate::parser::lenient::error(s, err))
                     .map(|(_, x)| x)
             }
         }
@@ -161,18 +240,21 @@ pub(crate) fn impl_macro(ast: &syn::DeriveInput) -> syn::Result<proc_macro2::Tok
             }
 
             fn ical(&self) -> crate::Result<::std::string::String> {
-                let mut s = String::new();
+                let mut s = Vec::new();
+                self.write(&mut s)?;
+
+                String::from_utf8(s).map_err(|e| crate::Error::Serialize(e.to_string()))
+            }
+
+            fn write(&self, writer: &mut dyn ::std::io::Write) -> crate::Result {
                 let name = Self::component().unwrap();
 
-                s.push_str("BEGIN:");
-                s.push_str(&name);
-                s.push_str("\r\n");
+                write!(writer, "BEGIN:{name}\r\n")?;
                 #(#ser_body)*
-                s.push_str("END:");
-                s.push_str(&name);
-                s.push_str("\r\n");
+                #(#ser_components)*
+                write!(writer, "END:{name}\r\n")?;
 
-                Ok(s)
+                Ok(())
             }
         }
     };
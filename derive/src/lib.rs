@@ -48,6 +48,10 @@ fn is_option(ty: &syn::Type) -> bool {
     tyname(ty) == "Option"
 }
 
+fn is_map(ty: &syn::Type) -> bool {
+    tyname(ty).ends_with("BTreeMap")
+}
+
 fn is_vec(ty: &syn::Type) -> bool {
     tyname(ty) == "Vec"
 }
@@ -177,12 +177,7 @@ impl quote::ToTokens for TypedValue {
                 };
 
                 let v = if Type::should_parsed(&self.0, &self.1) {
-                    if self.1 == "duration" {
-                        let ikal = ikal();
-                        quote::quote! { #ikal::parse_duration(#v)? }
-                    } else {
-                        quote::quote! { #v.parse()? }
-                    }
+                    quote::quote! { #v.parse()? }
                 } else if Type::should_convert(&self.0, &self.1) {
                     quote::quote! { #v.into() }
                 } else {
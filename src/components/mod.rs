@@ -1,6 +1,7 @@
 /**
  * See [3.6. Calendar Components](https://datatracker.ietf.org/doc/html/rfc5545#section-3.6)
  */
+mod raw;
 mod vcalendar;
 mod vevent;
 mod vfreebusy;
@@ -10,6 +11,7 @@ mod vtodo;
 pub mod valarm;
 pub mod vtimezone;
 
+pub use raw::*;
 pub use valarm::VAlarm;
 pub use vcalendar::*;
 pub use vevent::*;
@@ -18,12 +20,17 @@ pub use vjournal::*;
 pub use vtimezone::VTimezone;
 pub use vtodo::*;
 
+/**
+ * A component of a calendar.
+ */
 #[derive(Debug, PartialEq)]
-pub(crate) enum Component {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Component {
     Alarm(crate::VAlarm),
     Event(crate::VEvent),
     Freebusy(crate::VFreebusy),
     Journal(crate::VJournal),
+    Raw(crate::RawComponent),
     Timezone(crate::VTimezone),
     Todo(crate::VTodo),
 }
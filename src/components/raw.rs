@@ -0,0 +1,123 @@
+/**
+ * A component unknown to this crate, like `VAVAILABILITY` or a `X-`
+ * component, kept unchanged.
+ *
+ * Its sub-components are raw components too, whatever their name.
+ *
+ * See [3.6. Calendar Components](https://datatracker.ietf.org/doc/html/rfc5545#section-3.6)
+ */
+#[derive(Clone, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RawComponent {
+    pub name: String,
+    pub properties: Vec<crate::ContentLine>,
+    pub components: Vec<RawComponent>,
+}
+
+impl std::fmt::Debug for RawComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let properties = self
+            .properties
+            .iter()
+            .map(|x| (&x.key, x))
+            .collect::<Vec<_>>();
+
+        f.debug_struct("RawComponent")
+            .field("name", &self.name)
+            .field("properties", &properties)
+            .field("components", &self.components)
+            .finish()
+    }
+}
+
+impl RawComponent {
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+impl TryFrom<String> for RawComponent {
+    type Error = crate::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<&str> for RawComponent {
+    type Error = crate::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl std::str::FromStr for RawComponent {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parser::raw(&crate::parser::unfold(s))
+            .map_err(|err| crate::parser::lenient::error(s, err))
+            .map(|(_, x)| x)
+    }
+}
+
+impl crate::ser::Serialize for RawComponent {
+    /**
+     * The name of a raw component is only known at runtime, it's written by
+     * [`crate::ser::Serialize::write`].
+     */
+    fn component() -> Option<String> {
+        Some(String::new())
+    }
+
+    fn ical(&self) -> crate::Result<String> {
+        let mut s = Vec::new();
+        self.write(&mut s)?;
+
+        String::from_utf8(s).map_err(|e| crate::Error::Serialize(e.to_string()))
+    }
+
+    fn write(&self, writer: &mut dyn std::io::Write) -> crate::Result {
+        write!(writer, "BEGIN:{}\r\n", self.name)?;
+
+        for property in &self.properties {
+            crate::ser::write_field(writer, &property.key, property)?;
+        }
+
+        for component in &self.components {
+            component.write(writer)?;
+        }
+
+        write!(writer, "END:{}\r\n", self.name)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn round_trip() -> crate::Result {
+        let ical = "BEGIN:X-VENDOR\r
+X-NAME;X-PARAM=1:value\r
+DTSTAMP:20150121T080000\r
+BEGIN:VEVENT\r
+UID:12345\r
+END:VEVENT\r
+END:X-VENDOR\r
+";
+        let component = crate::RawComponent::try_from(ical)?;
+
+        assert_eq!(component.name, "X-VENDOR");
+        assert_eq!(component.properties.len(), 2);
+        assert_eq!(component.components[0].name, "VEVENT");
+        similar_asserts::assert_eq!(crate::ser::ical(&component)?, ical);
+
+        Ok(())
+    }
+}
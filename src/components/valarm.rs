@@ -2,6 +2,7 @@
  * See [3.6.6. Alarm Component](https://datatracker.ietf.org/doc/html/rfc5545#section-3.6.6)
  */
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VAlarm {
     Audio(Audio),
     Display(Display),
@@ -12,18 +13,41 @@ impl TryFrom<Vec<crate::ContentLine>> for VAlarm {
     type Error = crate::Error;
 
     fn try_from(properties: Vec<crate::ContentLine>) -> crate::Result<Self> {
-        let action = properties.iter().find(|x| x.key == "ACTION").unwrap();
+        Self::from_content_lines(properties, &mut |_, err| Err(err))
+    }
+}
+
+impl VAlarm {
+    /**
+     * See the `from_content_lines` function generated by [`crate::Component`].
+     */
+    pub(crate) fn from_content_lines(
+        properties: Vec<crate::ContentLine>,
+        on_error: &mut dyn FnMut(crate::ContentLine, crate::Error) -> crate::Result,
+    ) -> crate::Result<Self> {
+        let action = properties
+            .iter()
+            .find(|x| x.key == "ACTION")
+            .ok_or_else(|| crate::Error::Missing("ACTION".to_string()))?;
 
         let component = match action.value.as_str() {
-            "AUDIO" => Self::Audio(Audio::try_from(properties)?),
-            "DISPLAY" => Self::Display(Display::try_from(properties)?),
-            "EMAIL" => Self::Email(Email::try_from(properties)?),
+            "AUDIO" => Self::Audio(Audio::from_content_lines(properties, on_error)?),
+            "DISPLAY" => Self::Display(Display::from_content_lines(properties, on_error)?),
+            "EMAIL" => Self::Email(Email::from_content_lines(properties, on_error)?),
 
             action => return Err(crate::Error::Alarm(action.to_string())),
         };
 
         Ok(component)
     }
+
+    pub(crate) fn push_raw(&mut self, component: crate::RawComponent) {
+        match self {
+            Self::Audio(audio) => audio.push_raw(component),
+            Self::Display(display) => display.push_raw(component),
+            Self::Email(email) => email.push_raw(component),
+        }
+    }
 }
 
 impl TryFrom<String> for VAlarm {
@@ -46,13 +70,17 @@ impl std::str::FromStr for VAlarm {
     type Err = crate::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        crate::parser::valarm(&s.replace("\r\n ", ""))
-            .map_err(crate::Error::from)
+        crate::parser::valarm(&crate::parser::unfold(s))
+            .map_err(|err| crate::parser::lenient::error(s, err))
             .map(|(_, x)| x)
     }
 }
 
 impl crate::ser::Serialize for VAlarm {
+    fn component() -> Option<String> {
+        "VALARM".to_string().into()
+    }
+
     fn ical(&self) -> crate::Result<String> {
         let s = match self {
             Self::Audio(audio) => audio.ical(),
@@ -71,16 +99,21 @@ impl crate::ser::Serialize for VAlarm {
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, crate::Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Audio {
     pub action: crate::Text,
     pub trigger: crate::Trigger,
-    pub duration: Option<chrono::Duration>,
+    pub duration: Option<crate::Duration>,
     pub repeat: Option<u32>,
-    pub attach: Vec<crate::Text>,
+    pub attach: Vec<crate::Attachment>,
     #[component(ignore)]
     pub x_prop: std::collections::BTreeMap<String, crate::ContentLine>,
     #[component(ignore)]
     pub iana_prop: std::collections::BTreeMap<String, crate::ContentLine>,
+    #[component(ignore)]
+    pub x_comp: Vec<crate::RawComponent>,
+    #[component(ignore)]
+    pub iana_comp: Vec<crate::RawComponent>,
 }
 
 impl Audio {
@@ -99,16 +132,21 @@ impl From<Audio> for VAlarm {
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, crate::Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Display {
     pub action: crate::Text,
     pub trigger: crate::Trigger,
     pub description: crate::Text,
-    pub duration: Option<chrono::Duration>,
+    pub duration: Option<crate::Duration>,
     pub repeat: Option<u32>,
     #[component(ignore)]
     pub x_prop: std::collections::BTreeMap<String, crate::ContentLine>,
     #[component(ignore)]
     pub iana_prop: std::collections::BTreeMap<String, crate::ContentLine>,
+    #[component(ignore)]
+    pub x_comp: Vec<crate::RawComponent>,
+    #[component(ignore)]
+    pub iana_comp: Vec<crate::RawComponent>,
 }
 
 impl Display {
@@ -127,19 +165,24 @@ impl From<Display> for VAlarm {
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, crate::Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Email {
     pub action: crate::Text,
     pub trigger: crate::Trigger,
     pub description: crate::Text,
     pub summary: crate::Text,
-    pub attendee: Vec<crate::Uri>,
-    pub duration: Option<chrono::Duration>,
+    pub attendee: Vec<crate::CalAddress>,
+    pub duration: Option<crate::Duration>,
     pub repeat: Option<u32>,
-    pub attach: Vec<crate::Text>,
+    pub attach: Vec<crate::Attachment>,
     #[component(ignore)]
     pub x_prop: std::collections::BTreeMap<String, crate::ContentLine>,
     #[component(ignore)]
     pub iana_prop: std::collections::BTreeMap<String, crate::ContentLine>,
+    #[component(ignore)]
+    pub x_comp: Vec<crate::RawComponent>,
+    #[component(ignore)]
+    pub iana_comp: Vec<crate::RawComponent>,
 }
 
 impl Email {
@@ -166,7 +209,7 @@ mod test {
 
     #[test]
     fn ser() -> crate::Result {
-        let mut attach = crate::Text::from("ftp://example.com/pub/sounds/bell-01.aud");
+        let mut attach = crate::Uri::from("ftp://example.com/pub/sounds/bell-01.aud");
         attach
             .params
             .insert("FMTTYPE".to_string(), "audio/basic".to_string());
@@ -174,7 +217,7 @@ mod test {
         let valarm = crate::valarm! {
             @audio,
             trigger: "19970317T133000Z",
-            duration: "P15DT",
+            duration: "P15D",
             repeat: 4,
             attach: [attach],
         }?;
@@ -186,19 +229,20 @@ mod test {
             "BEGIN:VALARM\r
 ACTION:AUDIO\r
 TRIGGER;VALUE=DATE-TIME:19970317T133000Z\r
-DURATION:PT1296000S\r
+DURATION:P15D\r
 REPEAT:4\r
 ATTACH;FMTTYPE=audio/basic:ftp://example.com/pub/sounds/bell-01.aud\r
 END:VALARM\r
 "
         );
+        similar_asserts::assert_eq!(crate::VAlarm::try_from(ical.as_str())?, valarm);
 
         Ok(())
     }
 
     #[test]
     fn macros() -> crate::Result {
-        let duration = Some(chrono::TimeDelta::zero());
+        let duration = Some(crate::Duration::default());
 
         let _audio = crate::valarm! {
             @audio,
@@ -1,7 +1,10 @@
+use std::collections::{BTreeMap, BTreeSet};
+
 /**
  * See [3.6. Calendar Components](https://datatracker.ietf.org/doc/html/rfc5545#section-3.4)
  */
 #[derive(Clone, Debug, Default, PartialEq, crate::Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VCalendar {
     pub prodid: crate::Text,
     pub version: crate::Text,
@@ -23,6 +26,10 @@ pub struct VCalendar {
     pub x_prop: std::collections::BTreeMap<String, crate::ContentLine>,
     #[component(ignore)]
     pub iana_prop: std::collections::BTreeMap<String, crate::ContentLine>,
+    #[component(ignore)]
+    pub x_comp: Vec<crate::RawComponent>,
+    #[component(ignore)]
+    pub iana_comp: Vec<crate::RawComponent>,
 }
 
 impl VCalendar {
@@ -30,6 +37,429 @@ impl VCalendar {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /**
+     * Parses `input` without failing on invalid data.
+     *
+     * The invalid properties and components are skipped, they are returned
+     * with the reason of their rejection. A component is dropped when one
+     * of its required properties is missing or invalid.
+     *
+     * ```
+     * let (vcalendar, diagnostics) = ikal::VCalendar::parse_lenient("BEGIN:VCALENDAR
+     * VERSION:2.0
+     * PRODID:-//ikal//lenient//EN
+     * BEGIN:VEVENT
+     * UID:1
+     * DTSTAMP:20240101T000000Z
+     * DTSTART:20240101T090000
+     * PRIORITY:42
+     * END:VEVENT
+     * END:VCALENDAR
+     * ");
+     *
+     * assert_eq!(vcalendar.events.len(), 1);
+     * assert_eq!(diagnostics[0].error.line, 8);
+     * assert_eq!(diagnostics[0].error.path, "VCALENDAR > VEVENT[0] > PRIORITY");
+     * ```
+     */
+    #[must_use]
+    pub fn parse_lenient(input: &str) -> (Self, Vec<crate::Diagnostic>) {
+        crate::parser::lenient::vcalendar(input)
+    }
+
+    /**
+     * Returns the time zone definition of `tzid`.
+     */
+    #[must_use]
+    pub fn timezone(&self, tzid: &str) -> Option<&crate::VTimezone> {
+        self.timezones.iter().find(|x| x.tzid.as_ref() == tzid)
+    }
+
+    /**
+     * Adds the missing definitions of the time zones referenced by the
+     * components, generated for the `years` range from the zoneinfo files.
+     *
+     * Call it before serializing a calendar built with `TZID` date-times,
+     * see [`crate::VTimezone::from_zoneinfo`].
+     */
+    pub fn insert_timezones(&mut self, years: std::ops::RangeInclusive<i32>) -> crate::Result {
+        for tzid in self.tzids() {
+            if self.timezone(&tzid).is_none() {
+                let vtimezone = crate::VTimezone::from_zoneinfo(&tzid, years.clone())?;
+                self.timezones.push(vtimezone);
+            }
+        }
+
+        Ok(())
+    }
+
+    /**
+     * `TZID` referenced by the components.
+     */
+    fn tzids(&self) -> BTreeSet<String> {
+        let events = self.events.iter().map(|x| {
+            (
+                vec![
+                    Some(&x.dtstart),
+                    x.dtend.as_ref(),
+                    x.recurid.as_ref().map(|x| &x.date),
+                ],
+                &x.exdate,
+                &x.rdate,
+            )
+        });
+        let todos = self.todo.iter().map(|x| {
+            (
+                vec![
+                    x.dtstart.as_ref(),
+                    x.due.as_ref(),
+                    x.recurid.as_ref().map(|x| &x.date),
+                ],
+                &x.exdate,
+                &x.rdate,
+            )
+        });
+        let journals = self.journals.iter().map(|x| {
+            (
+                vec![Some(&x.dtstart), x.recurid.as_ref().map(|x| &x.date)],
+                &x.exdate,
+                &x.rdate,
+            )
+        });
+
+        events
+            .chain(todos)
+            .chain(journals)
+            .flat_map(|(dates, exdate, rdate)| {
+                dates
+                    .into_iter()
+                    .flatten()
+                    .chain(exdate)
+                    .filter_map(crate::Date::tzid)
+                    .chain(rdate.iter().filter_map(crate::RDate::tzid))
+                    .map(String::from)
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /**
+     * Converts `date_time` to an absolute instant, resolving its `TZID`
+     * against the time zones of this calendar.
+     *
+     * Floating date-times are interpreted in the local time zone.
+     */
+    pub fn to_utc(
+        &self,
+        date_time: &crate::DateTime,
+    ) -> crate::Result<chrono::DateTime<chrono::Utc>> {
+        match date_time {
+            crate::DateTime::Naive(_) => {
+                chrono::DateTime::<chrono::Local>::try_from(date_time.clone()).map(|x| x.to_utc())
+            }
+            crate::DateTime::Local(local) => Ok(local.to_utc()),
+            crate::DateTime::Utc(utc) => Ok(*utc),
+            crate::DateTime::Zoned(naive, tzid) => self
+                .timezone(tzid)
+                .and_then(|x| x.to_utc(*naive))
+                .ok_or_else(|| crate::Error::Timezone(tzid.clone())),
+        }
+    }
+
+    /**
+     * Expands the events starting between `start` (inclusive) and `end`
+     * (exclusive), ordered by start.
+     *
+     * Events are grouped by `UID`: the instances of the recurring event are
+     * replaced by the events with a `RECURRENCE-ID` at the same instant, a
+     * `RANGE=THISANDFUTURE` override applies its changes and time shift to
+     * all the following instances, and cancelled instances are dropped.
+     * The overrides matching no instance are kept as they are.
+     */
+    #[must_use]
+    pub fn events_between<D: Into<crate::Date>>(&self, start: D, end: D) -> Vec<crate::VEvent> {
+        let start = start.into();
+        let end = end.into();
+
+        let mut events = self
+            .instances(&end)
+            .into_iter()
+            .filter(|x| x.dtstart >= start && x.dtstart < end)
+            .collect::<Vec<_>>();
+
+        // the start of different time zones are compared by instant
+        events.sort_by_cached_key(|x| {
+            let utc = self
+                .to_utc(&x.dtstart.clone().into())
+                .unwrap_or_else(|_| x.dtstart.naive().and_utc());
+
+            (utc, x.dtstart.clone())
+        });
+
+        events
+    }
+
+    /**
+     * Computes the busy time of the events between `start` and `end`, as
+     * the reply of a free/busy request for `attendee`.
+     *
+     * The instances are expanded like [`Self::events_between`], the
+     * transparent and cancelled ones are skipped. The overlapping periods
+     * are merged, the tentative events are reported as `BUSY-TENTATIVE`.
+     *
+     * The time zones without `VTIMEZONE` are read from the zoneinfo files,
+     * see [`crate::VTimezone::from_zoneinfo`], the instances of an unknown
+     * time zone are skipped.
+     *
+     * See [4.4.1. Request Busy Time](https://datatracker.ietf.org/doc/html/rfc5546#section-4.4.1)
+     */
+    pub fn freebusy(
+        &self,
+        start: chrono::DateTime<chrono::Utc>,
+        end: chrono::DateTime<chrono::Utc>,
+        attendee: Option<crate::CalAddress>,
+    ) -> crate::Result<crate::VFreebusy> {
+        let mut busy = Vec::new();
+        let mut tentative = Vec::new();
+
+        // the events are bounded with their wall time, before their time zone is known
+        let bound = crate::Date::from(end) + chrono::TimeDelta::days(1);
+
+        let instances = self.instances(&bound);
+
+        let mut zoneinfo = BTreeMap::new();
+        for event in &instances {
+            use chrono::Datelike as _;
+
+            let tzids = [Some(&event.dtstart), event.dtend.as_ref()]
+                .into_iter()
+                .flatten()
+                .filter_map(crate::Date::tzid)
+                .filter(|x| self.timezone(x).is_none());
+
+            for tzid in tzids {
+                zoneinfo.entry(tzid).or_insert_with(|| {
+                    let years = (start.year() - 1)..=(end.year() + 1);
+
+                    crate::VTimezone::from_zoneinfo(tzid, years).ok()
+                });
+            }
+        }
+
+        let timezone = |tzid: &str| self.timezone(tzid).or_else(|| zoneinfo.get(tzid)?.as_ref());
+        let utc = |date: crate::Date| match crate::DateTime::from(date) {
+            crate::DateTime::Zoned(naive, tzid) => timezone(&tzid)?.to_utc(naive),
+            date_time => self.to_utc(&date_time).ok(),
+        };
+
+        for event in &instances {
+            if event.transp == Some(crate::TimeTransparency::Transparent) {
+                continue;
+            }
+
+            let event_end = match event.dtstart.tzid().and_then(timezone) {
+                Some(vtimezone) => event.end_in(vtimezone),
+                None => event.end(),
+            };
+
+            let (Some(event_start), Some(event_end)) = (utc(event.dtstart.clone()), utc(event_end))
+            else {
+                continue;
+            };
+            let (event_start, event_end) = (event_start.max(start), event_end.min(end));
+
+            if event_start >= event_end {
+                continue;
+            }
+
+            if event.status == Some(crate::Status::Tentative) {
+                tentative.push((event_start, event_end));
+            } else {
+                busy.push((event_start, event_end));
+            }
+        }
+
+        let freebusy = [
+            (crate::FbType::Busy, busy),
+            (crate::FbType::BusyTentative, tentative),
+        ]
+        .into_iter()
+        .filter(|(_, intervals)| !intervals.is_empty())
+        .map(|(fbtype, intervals)| {
+            let periods = super::vfreebusy::merge(intervals)
+                .into_iter()
+                .map(|(start, end)| {
+                    crate::Period::StartEnd(crate::period::StartEnd {
+                        start: start.into(),
+                        end: end.into(),
+                    })
+                })
+                .collect();
+
+            crate::FreeBusy::new(fbtype, periods)
+        })
+        .collect();
+
+        Ok(crate::VFreebusy {
+            dtstamp: chrono::Utc::now().into(),
+            dtstart: Some(start.into()),
+            dtend: Some(end.into()),
+            attendee: attendee.into_iter().collect(),
+            freebusy,
+            ..Default::default()
+        })
+    }
+
+    /**
+     * Expands the events starting before `end`, the cancelled instances
+     * are dropped.
+     */
+    fn instances(&self, end: &crate::Date) -> Vec<crate::VEvent> {
+        let mut groups = BTreeMap::<&str, (Option<&crate::VEvent>, Vec<&crate::VEvent>)>::new();
+
+        for event in &self.events {
+            let group = groups.entry(event.uid.as_ref()).or_default();
+
+            if event.recurid.is_some() {
+                group.1.push(event);
+            } else {
+                group.0 = Some(event);
+            }
+        }
+
+        groups
+            .into_values()
+            .flat_map(|(master, overrides)| {
+                let timezone = master
+                    .and_then(|x| x.dtstart.tzid())
+                    .and_then(|x| self.timezone(x));
+
+                expand(master, &overrides, timezone, end, |x| self.instant(x))
+            })
+            .filter(|x| x.status != Some(crate::Status::Cancelled))
+            .collect()
+    }
+
+    /**
+     * Resolves `date` to the UTC instant matching the recurrence
+     * identifiers, the wall time is kept for the dates, the floating
+     * date-times and the unknown time zones.
+     */
+    fn instant(&self, date: &crate::Date) -> chrono::NaiveDateTime {
+        match date {
+            crate::Date::DateTime(
+                date_time @ (crate::DateTime::Local(_)
+                | crate::DateTime::Utc(_)
+                | crate::DateTime::Zoned(..)),
+            ) => self
+                .to_utc(date_time)
+                .map_or_else(|_| date.naive(), |x| x.naive_utc()),
+            _ => date.naive(),
+        }
+    }
+}
+
+/**
+ * Expands the instances of `master` starting before `end` and applies the
+ * `overrides` of the same `UID`, the `TZID` of `master` is resolved with
+ * `timezone`.
+ *
+ * The `RECURRENCE-ID` are compared to the instances by the instant from
+ * `instant`. The overrides matching no instance, moved from after the
+ * window or orphaned by the rule, are kept as standalone events.
+ */
+fn expand(
+    master: Option<&crate::VEvent>,
+    overrides: &[&crate::VEvent],
+    timezone: Option<&crate::VTimezone>,
+    end: &crate::Date,
+    instant: impl Fn(&crate::Date) -> chrono::NaiveDateTime,
+) -> Vec<crate::VEvent> {
+    let recurid = |event: &crate::VEvent| event.recurid.clone().unwrap_or_default();
+
+    let Some(master) = master else {
+        return overrides.iter().map(|x| (*x).clone()).collect();
+    };
+
+    let keys = overrides
+        .iter()
+        .map(|x| {
+            let recurid = recurid(x);
+
+            (
+                instant(&recurid.date),
+                recurid.range == Some(crate::Range::ThisAndFuture),
+            )
+        })
+        .collect::<Vec<_>>();
+    let mut matched = vec![false; overrides.len()];
+    let mut events = Vec::new();
+
+    for instance in master
+        .recurrent()
+        .with_timezone(timezone)
+        .take_while(|x| &x.dtstart < end)
+    {
+        let start = instant(&instance.dtstart);
+        let mut exact = None::<usize>;
+        let mut range = None::<usize>;
+
+        for (i, (key, this_and_future)) in keys.iter().enumerate() {
+            if *key == start {
+                matched[i] = true;
+
+                if exact.is_none_or(|x| overrides[x].sequence <= overrides[i].sequence) {
+                    exact = Some(i);
+                }
+            }
+
+            if *this_and_future && *key <= start {
+                matched[i] = true;
+
+                if range.is_none_or(|x| {
+                    (keys[x].0, overrides[x].sequence) <= (*key, overrides[i].sequence)
+                }) {
+                    range = Some(i);
+                }
+            }
+        }
+
+        let event = match (exact, range) {
+            (Some(i), _) => overrides[i].clone(),
+            (None, Some(i)) => this_and_future(overrides[i], &instance),
+            (None, None) => instance,
+        };
+
+        events.push(event);
+    }
+
+    events.extend(
+        overrides
+            .iter()
+            .zip(matched)
+            .filter(|(_, matched)| !matched)
+            .map(|(x, _)| (*x).clone()),
+    );
+
+    events
+}
+
+/**
+ * Applies a `RANGE=THISANDFUTURE` override to a later `instance`.
+ */
+fn this_and_future(event: &crate::VEvent, instance: &crate::VEvent) -> crate::VEvent {
+    let recurid = event.recurid.clone().unwrap_or_default();
+    let offset = event.dtstart.naive() - recurid.date.naive();
+
+    let mut occurrence = event.clone();
+    occurrence.dtstart = event.dtstart.with_naive(instance.dtstart.naive() + offset);
+    occurrence.dtend = event.dtend.as_ref().map(|dtend| {
+        dtend.with_naive(occurrence.dtstart.naive() + (dtend.naive() - event.dtstart.naive()))
+    });
+    occurrence.recurid = Some(instance.dtstart.clone().into());
+
+    occurrence
 }
 
 #[cfg(test)]
@@ -39,6 +469,555 @@ mod test {
         crate::test::test_files::<crate::VCalendar>("calendars")
     }
 
+    #[test]
+    fn escaped_method() -> crate::Result {
+        let input = "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+PRODID:-//ikal//test//EN\r
+METHOD:X-PUBLISH\\,DRAFT\r
+END:VCALENDAR\r
+";
+        let vcalendar = crate::VCalendar::try_from(input)?;
+        assert_eq!(vcalendar.method.as_deref(), Some("X-PUBLISH,DRAFT"));
+
+        let ical = crate::ser::ical(&vcalendar)?;
+        assert!(ical.contains("METHOD:X-PUBLISH\\,DRAFT\r\n"));
+        similar_asserts::assert_eq!(crate::VCalendar::try_from(ical.as_str())?, vcalendar);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_error() {
+        let mut input = "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+PRODID:-//ikal//test//EN\r
+"
+        .to_string();
+
+        for uid in 0..4 {
+            let dtstart = if uid == 3 { "20241340" } else { "20241201" };
+
+            input.push_str(&format!(
+                "BEGIN:VEVENT\r
+UID:{uid}\r
+DTSTAMP:20240101T000000Z\r
+DTSTART;VALUE=DATE:{dtstart}\r
+END:VEVENT\r
+"
+            ));
+        }
+        input.push_str("END:VCALENDAR\r\n");
+
+        let Err(crate::Error::Parse(err)) = crate::VCalendar::try_from(input) else {
+            panic!("Invalid calendar parsed");
+        };
+
+        assert_eq!(err.line, 22);
+        assert_eq!(err.column, 20);
+        assert_eq!(err.path, "VCALENDAR > VEVENT[3] > DTSTART");
+        assert_eq!(err.property.as_deref(), Some("DTSTART"));
+        assert_eq!(
+            err.to_string(),
+            "line 22, column 20: VCALENDAR > VEVENT[3] > DTSTART: Invalid value \"20241340\": Expected date_or_dt, found \"20241340\""
+        );
+
+        let Err(crate::Error::Parse(err)) = crate::VCalendar::try_from(
+            "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+PRODID:-//ikal//test//EN\r
+BEGIN:VTODO\r
+UID:1\r
+ 2\r
+DTSTAMP\r
+END:VTODO\r
+END:VCALENDAR\r
+",
+        ) else {
+            panic!("Invalid calendar parsed");
+        };
+
+        assert_eq!((err.line, err.column), (7, 1));
+        assert_eq!(err.path, "VCALENDAR > VTODO[0]");
+        assert_eq!(err.property, None);
+    }
+
+    #[test]
+    fn parse_lenient() -> crate::Result {
+        let tests = std::path::Path::new("tests/calendars");
+
+        for entry in tests.read_dir().unwrap() {
+            let file = entry.unwrap().path();
+
+            if file.extension() != Some(std::ffi::OsStr::new("ics")) {
+                continue;
+            }
+
+            let input = std::fs::read_to_string(&file).unwrap();
+
+            if let Ok(expected) = crate::VCalendar::try_from(input.as_str()) {
+                let (vcalendar, _) = crate::VCalendar::parse_lenient(&input);
+                similar_asserts::assert_eq!(vcalendar, expected, "{file:?}");
+            }
+        }
+
+        let input = std::fs::read_to_string("tests/calendars/small_bad_calendar.ics").unwrap();
+        let (vcalendar, diagnostics) = crate::VCalendar::parse_lenient(&input);
+        assert!(vcalendar.events.is_empty());
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            [
+                "line 3, column 1: VCALENDAR: Missing END:VCALENDAR",
+                "line 1, column 1: VCALENDAR > PRODID: Missing field PRODID",
+                "line 1, column 1: VCALENDAR > VERSION: Missing field VERSION",
+                "line 2, column 1: VCALENDAR > VEVENT[0] > DTSTAMP: Missing field DTSTAMP",
+            ]
+        );
+        assert_eq!(diagnostics[3].content_lines.len(), 2);
+
+        let input =
+            std::fs::read_to_string("tests/calendars/issue_104_broken_calendar.ics").unwrap();
+        let (vcalendar, diagnostics) = crate::VCalendar::parse_lenient(&input);
+        assert_eq!(vcalendar.method.as_deref(), Some("PUBLISH"));
+        assert_eq!(diagnostics[0].error.path, "VCALENDAR");
+        assert_eq!(
+            diagnostics[0].content_lines,
+            [crate::ContentLine::from("X")]
+        );
+
+        let (vcalendar, diagnostics) = crate::VCalendar::parse_lenient(
+            "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+PRODID:-//ikal//test//EN\r
+BEGIN:VEVENT\r
+UID:1\r
+DTSTAMP:20240101T000000Z\r
+DTSTART:20240101T090000\r
+STATUS:UNKNOWN\r
+PRIORITY:12\r
+SUMMARY:Valid\r
+BEGIN:VALARM\r
+TRIGGER:-PT15M\r
+END:VALARM\r
+END:VEVENT\r
+BEGIN:VTODO\r
+UID:2\r
+DTSTAMP:20240101T000000Z\r
+END:VTODO\r
+END:VCALENDAR\r
+",
+        );
+        assert_eq!(vcalendar.events.len(), 1);
+        assert_eq!(vcalendar.events[0].summary.as_deref(), Some("Valid"));
+        assert_eq!(vcalendar.events[0].status, None);
+        assert_eq!(vcalendar.todo.len(), 1);
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|x| (x.to_string(), x.content_lines.len()))
+                .collect::<Vec<_>>(),
+            [
+                (
+                    "line 8, column 8: VCALENDAR > VEVENT[0] > STATUS: Invalid value \"UNKNOWN\": Unknow status UNKNOWN".to_string(),
+                    1
+                ),
+                (
+                    "line 9, column 10: VCALENDAR > VEVENT[0] > PRIORITY: Invalid value \"12\": Invalid priority: 12".to_string(),
+                    1
+                ),
+                (
+                    "line 11, column 1: VCALENDAR > VEVENT[0] > VALARM[0] > ACTION: Missing field ACTION".to_string(),
+                    3
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn line_endings() -> crate::Result {
+        let tests = std::path::Path::new("tests/calendars");
+
+        for entry in tests.read_dir().unwrap() {
+            let file = entry.unwrap().path();
+
+            if file.extension() != Some(std::ffi::OsStr::new("ics")) {
+                continue;
+            }
+
+            let input = std::fs::read_to_string(&file).unwrap();
+            let Ok(expected) = crate::VCalendar::try_from(input.as_str()) else {
+                continue;
+            };
+
+            let lf = format!("\u{feff}{}", input.replace("\r\n", "\n"));
+            similar_asserts::assert_eq!(
+                crate::VCalendar::try_from(lf.as_str())?,
+                expected,
+                "{file:?}"
+            );
+
+            let tab = lf.replace("\n ", "\n\t");
+            similar_asserts::assert_eq!(crate::VCalendar::try_from(tab)?, expected, "{file:?}");
+
+            let mixed = input
+                .split("\r\n")
+                .enumerate()
+                .map(|(n, line)| {
+                    if n % 2 == 0 {
+                        format!("{line}\n")
+                    } else {
+                        format!("{line}\r\n")
+                    }
+                })
+                .collect::<String>();
+            similar_asserts::assert_eq!(crate::VCalendar::try_from(mixed)?, expected, "{file:?}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn to_utc() -> crate::Result {
+        let input = std::fs::read_to_string("tests/calendars/period_with_timezone.ics").unwrap();
+        let vcalendar = crate::VCalendar::try_from(input)?;
+        let event = &vcalendar.events[0];
+
+        let dtstart = crate::DateTime::from(event.dtstart.clone());
+        assert_eq!(
+            crate::DateTime::Utc(vcalendar.to_utc(&dtstart)?),
+            "20230920T190000Z".parse()?
+        );
+
+        let crate::RDate::Period(periods) = &event.rdate[0] else {
+            unreachable!();
+        };
+        assert_eq!(
+            crate::DateTime::Utc(vcalendar.to_utc(&periods[0].start())?),
+            "20231213T200000Z".parse()?
+        );
+
+        let unknown = crate::DateTime::Zoned(dtstart.naive(), "Europe/Paris".to_string());
+        assert_eq!(
+            vcalendar.to_utc(&unknown),
+            Err(crate::Error::Timezone("Europe/Paris".to_string()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn insert_timezones() -> crate::Result {
+        let mut vcalendar = crate::vcalendar! {
+            prodid: "-//ikal//test//EN",
+            version: "2.0",
+            events: [
+                {
+                    uid: "tzid@example.com",
+                    dtstamp: "20240101T000000Z",
+                    dtstart: "20240101T090000",
+                }
+            ],
+        }?;
+        vcalendar.events[0].dtstart = crate::DateTime::Zoned(
+            vcalendar.events[0].dtstart.naive(),
+            "Europe/Paris".to_string(),
+        )
+        .into();
+
+        vcalendar.insert_timezones(2024..=2030)?;
+        vcalendar.insert_timezones(2024..=2030)?;
+
+        assert_eq!(vcalendar.timezones.len(), 1);
+        assert_eq!(
+            crate::DateTime::Utc(vcalendar.to_utc(&vcalendar.events[0].dtstart.clone().into())?),
+            "20240101T080000Z".parse()?
+        );
+
+        let ical = crate::ser::ical(&vcalendar)?;
+        assert!(ical.contains("BEGIN:VTIMEZONE\r\nTZID:Europe/Paris\r\n"));
+        assert!(ical.contains("DTSTART;TZID=Europe/Paris:20240101T090000\r\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn events_between() -> crate::Result {
+        let vcalendar = crate::VCalendar::try_from(
+            "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+PRODID:-//ikal//test//EN\r
+BEGIN:VEVENT\r
+UID:weekly@example.com\r
+DTSTAMP:20240101T000000\r
+DTSTART:20240101T090000\r
+DTEND:20240101T100000\r
+SUMMARY:Weekly\r
+RRULE:FREQ=WEEKLY;COUNT=6\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:weekly@example.com\r
+DTSTAMP:20240101T000000\r
+RECURRENCE-ID:20240108T090000\r
+DTSTART:20240109T140000\r
+DTEND:20240109T150000\r
+SUMMARY:Moved\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:weekly@example.com\r
+DTSTAMP:20240101T000000\r
+RECURRENCE-ID:20240115T090000\r
+DTSTART:20240115T090000\r
+STATUS:CANCELLED\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:weekly@example.com\r
+DTSTAMP:20240101T000000\r
+RECURRENCE-ID;RANGE=THISANDFUTURE:20240122T090000\r
+DTSTART:20240122T110000\r
+DTEND:20240122T113000\r
+SUMMARY:Shorter\r
+END:VEVENT\r
+END:VCALENDAR\r
+",
+        )?;
+
+        let events = vcalendar
+            .events_between(
+                "20240101T000000".parse::<crate::Date>()?,
+                "20240206T000000".parse::<crate::Date>()?,
+            )
+            .into_iter()
+            .map(|x| {
+                (
+                    x.dtstart.to_string(),
+                    x.dtend.unwrap().to_string(),
+                    x.summary.unwrap().to_string(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let expected = [
+            ("20240101T090000", "20240101T100000", "Weekly"),
+            ("20240109T140000", "20240109T150000", "Moved"),
+            ("20240122T110000", "20240122T113000", "Shorter"),
+            ("20240129T110000", "20240129T113000", "Shorter"),
+            ("20240205T110000", "20240205T113000", "Shorter"),
+        ]
+        .map(|(start, end, summary)| (start.to_string(), end.to_string(), summary.to_string()));
+
+        assert_eq!(events, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn events_between_time_zones() -> crate::Result {
+        let vcalendar = crate::VCalendar::try_from(
+            "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+PRODID:-//ikal//test//EN\r
+BEGIN:VTIMEZONE\r
+TZID:America/New_York\r
+BEGIN:STANDARD\r
+DTSTART:20071104T020000\r
+RRULE:FREQ=YEARLY;BYMONTH=11;BYDAY=1SU\r
+TZOFFSETFROM:-0400\r
+TZOFFSETTO:-0500\r
+END:STANDARD\r
+END:VTIMEZONE\r
+BEGIN:VEVENT\r
+UID:new-york@example.com\r
+DTSTAMP:20240101T000000Z\r
+DTSTART;TZID=America/New_York:20240108T090000\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:utc@example.com\r
+DTSTAMP:20240101T000000Z\r
+DTSTART:20240108T120000Z\r
+END:VEVENT\r
+END:VCALENDAR\r
+",
+        )?;
+
+        let events = vcalendar
+            .events_between(
+                "20240108".parse::<crate::Date>()?,
+                "20240109".parse::<crate::Date>()?,
+            )
+            .into_iter()
+            .map(|x| x.uid.to_string())
+            .collect::<Vec<_>>();
+
+        // 09:00 in New York is 14:00 UTC
+        assert_eq!(events, ["utc@example.com", "new-york@example.com"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn events_between_recurrence_ids() -> crate::Result {
+        let vcalendar = crate::VCalendar::try_from(
+            "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+PRODID:-//ikal//test//EN\r
+BEGIN:VTIMEZONE\r
+TZID:America/New_York\r
+BEGIN:STANDARD\r
+DTSTART:20071104T020000\r
+TZOFFSETFROM:-0400\r
+TZOFFSETTO:-0500\r
+END:STANDARD\r
+END:VTIMEZONE\r
+BEGIN:VTIMEZONE\r
+TZID:Europe/Paris\r
+BEGIN:STANDARD\r
+DTSTART:19961027T030000\r
+TZOFFSETFROM:+0200\r
+TZOFFSETTO:+0100\r
+END:STANDARD\r
+END:VTIMEZONE\r
+BEGIN:VEVENT\r
+UID:weekly@example.com\r
+DTSTAMP:20240101T000000Z\r
+DTSTART;TZID=America/New_York:20240101T090000\r
+SUMMARY:Weekly\r
+RRULE:FREQ=WEEKLY;COUNT=4\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:weekly@example.com\r
+DTSTAMP:20240101T000000Z\r
+RECURRENCE-ID:20240108T140000Z\r
+DTSTART;TZID=America/New_York:20240108T100000\r
+SUMMARY:Utc\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:weekly@example.com\r
+DTSTAMP:20240101T000000Z\r
+RECURRENCE-ID;TZID=Europe/Paris:20240115T090000\r
+DTSTART;TZID=Europe/Paris:20240115T100000\r
+SUMMARY:Orphan\r
+END:VEVENT\r
+END:VCALENDAR\r
+",
+        )?;
+
+        let events = vcalendar
+            .events_between(
+                "20240101T000000Z".parse::<crate::Date>()?,
+                "20240123T000000Z".parse::<crate::Date>()?,
+            )
+            .into_iter()
+            .map(|x| (x.dtstart.to_string(), x.summary.unwrap().to_string()))
+            .collect::<Vec<_>>();
+
+        // the UTC identifier designates 09:00 in New York, the same wall time
+        // in Paris designates no instance
+        let expected = [
+            ("20240101T090000", "Weekly"),
+            ("20240108T100000", "Utc"),
+            ("20240115T100000", "Orphan"),
+            ("20240115T090000", "Weekly"),
+            ("20240122T090000", "Weekly"),
+        ]
+        .map(|(start, summary)| (start.to_string(), summary.to_string()));
+
+        assert_eq!(events, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn freebusy() -> crate::Result {
+        let vcalendar = crate::VCalendar::try_from(
+            "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+PRODID:-//ikal//test//EN\r
+BEGIN:VEVENT\r
+UID:weekly@example.com\r
+DTSTAMP:20240101T000000Z\r
+DTSTART:20240101T090000Z\r
+DTEND:20240101T100000Z\r
+RRULE:FREQ=WEEKLY;COUNT=3\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:overlap@example.com\r
+DTSTAMP:20240101T000000Z\r
+DTSTART:20240101T093000Z\r
+DTEND:20240101T110000Z\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:transparent@example.com\r
+DTSTAMP:20240101T000000Z\r
+DTSTART:20240102T100000Z\r
+DURATION:PT1H\r
+TRANSP:TRANSPARENT\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:cancelled@example.com\r
+DTSTAMP:20240101T000000Z\r
+DTSTART:20240103T100000Z\r
+DURATION:PT1H\r
+STATUS:CANCELLED\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:tentative@example.com\r
+DTSTAMP:20240101T000000Z\r
+DTSTART:20240104T140000Z\r
+DURATION:PT1H\r
+STATUS:TENTATIVE\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:before@example.com\r
+DTSTAMP:20240101T000000Z\r
+DTSTART:20231231T220000Z\r
+DTEND:20240101T083000Z\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:zoneinfo@example.com\r
+DTSTAMP:20240101T000000Z\r
+DTSTART;TZID=Europe/Paris:20240105T100000\r
+DURATION:PT1H\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:unknown@example.com\r
+DTSTAMP:20240101T000000Z\r
+DTSTART;TZID=Unknown/Zone:20240106T100000\r
+DURATION:PT1H\r
+END:VEVENT\r
+END:VCALENDAR\r
+",
+        )?;
+
+        let mut vfreebusy = vcalendar.freebusy(
+            "2024-01-01T08:00:00Z".parse()?,
+            "2024-01-10T00:00:00Z".parse()?,
+            Some("mailto:jdoe@example.com".into()),
+        )?;
+        vfreebusy.dtstamp = crate::DateTime::default();
+
+        similar_asserts::assert_eq!(
+            crate::ser::ical(&vfreebusy)?,
+            "BEGIN:VFREEBUSY\r
+DTSTAMP:19700101T000000\r
+DTSTART:20240101T080000Z\r
+DTEND:20240110T000000Z\r
+ATTENDEE:mailto:jdoe@example.com\r
+FREEBUSY;FBTYPE=BUSY:20240101T080000Z/20240101T083000Z,20240101T090000Z/202\r
+ 40101T110000Z,20240105T090000Z/20240105T100000Z,20240108T090000Z/20240108T\r
+ 100000Z\r
+FREEBUSY;FBTYPE=BUSY-TENTATIVE:20240104T140000Z/20240104T150000Z\r
+END:VFREEBUSY\r
+"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn ser() -> crate::Result {
         let vcalendar = crate::vcalendar! {
@@ -75,4 +1054,65 @@ END:VCALENDAR\r
 
         Ok(())
     }
+
+    #[test]
+    fn ser_raw_components() -> crate::Result {
+        let ical = "BEGIN:VCALENDAR\r
+PRODID:-//ikal//raw components//EN\r
+VERSION:2.0\r
+X-WR-CALNAME:Raw\r
+BEGIN:VEVENT\r
+DTSTAMP:20240101T000000Z\r
+UID:1\r
+DTSTART:20240101T090000\r
+BEGIN:X-VENDOR-DATA\r
+X-VENDOR-ID;X-SCOPE=event:42\r
+END:X-VENDOR-DATA\r
+END:VEVENT\r
+BEGIN:X-MYCOMP\r
+DESCRIPTION:Nested\\, escaped\r
+END:X-MYCOMP\r
+BEGIN:VAVAILABILITY\r
+UID:availability-1\r
+DTSTART:20240102T000000Z\r
+BEGIN:AVAILABLE\r
+UID:available-1\r
+RRULE:FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR\r
+END:AVAILABLE\r
+END:VAVAILABILITY\r
+END:VCALENDAR\r
+";
+        let vcalendar = crate::VCalendar::try_from(ical)?;
+
+        assert_eq!(vcalendar.events[0].x_comp[0].name, "X-VENDOR-DATA");
+        assert_eq!(vcalendar.x_comp[0].name, "X-MYCOMP");
+        assert_eq!(vcalendar.iana_comp[0].components[0].name, "AVAILABLE");
+        similar_asserts::assert_eq!(crate::ser::ical(&vcalendar)?, ical);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() -> crate::Result {
+        for (file, vcalendar) in crate::test::calendars() {
+            let json = serde_json::to_string(&vcalendar).unwrap();
+            let round_trip = serde_json::from_str::<crate::VCalendar>(&json).unwrap();
+
+            similar_asserts::assert_eq!(round_trip, vcalendar, "{file:?}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_diagnostics() {
+        let (_, diagnostics) = crate::VCalendar::parse_lenient("BEGIN:VCALENDAR\r\nX\r\n");
+
+        let json = serde_json::to_string(&diagnostics).unwrap();
+        let round_trip = serde_json::from_str::<Vec<crate::Diagnostic>>(&json).unwrap();
+
+        assert_eq!(round_trip, diagnostics);
+    }
 }
@@ -2,6 +2,7 @@
  * See [3.6.1. Event Component](https://datatracker.ietf.org/doc/html/rfc5545#section-3.6.1)
  */
 #[derive(Clone, Debug, Default, PartialEq, crate::Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VEvent {
     pub dtstamp: crate::DateTime,
     pub uid: crate::Text,
@@ -12,19 +13,20 @@ pub struct VEvent {
     pub geo: Option<crate::Geo>,
     pub last_modified: Option<crate::DateTime>,
     pub location: Option<crate::Text>,
-    pub organizer: Option<crate::Uri>,
+    pub organizer: Option<crate::CalAddress>,
     pub priority: Option<u8>,
     pub sequence: Option<u32>,
     pub status: Option<crate::Status>,
     pub summary: Option<crate::Text>,
     pub transp: Option<crate::TimeTransparency>,
     pub url: Option<crate::Uri>,
-    pub recurid: Option<crate::Date>,
+    #[component(rename = "RECURRENCE-ID")]
+    pub recurid: Option<crate::RecurId>,
     pub rrule: Option<crate::Recur>,
     pub dtend: Option<crate::Date>,
-    pub duration: Option<chrono::Duration>,
-    pub attach: Vec<crate::Text>,
-    pub attendee: Vec<crate::Uri>,
+    pub duration: Option<crate::Duration>,
+    pub attach: Vec<crate::Attachment>,
+    pub attendee: Vec<crate::CalAddress>,
     #[component(append)]
     pub categories: Vec<crate::Text>,
     pub comment: Vec<crate::Text>,
@@ -42,6 +44,10 @@ pub struct VEvent {
     pub x_prop: std::collections::BTreeMap<String, crate::ContentLine>,
     #[component(ignore)]
     pub iana_prop: std::collections::BTreeMap<String, crate::ContentLine>,
+    #[component(ignore)]
+    pub x_comp: Vec<crate::RawComponent>,
+    #[component(ignore)]
+    pub iana_comp: Vec<crate::RawComponent>,
 }
 
 impl VEvent {
@@ -54,6 +60,41 @@ impl VEvent {
     pub fn recurrent(&self) -> crate::iter::Recur<Self> {
         crate::iter::Recur::from(self)
     }
+
+    /**
+     * The effective end of the event: the `DTEND`, the `DTSTART` plus the
+     * `DURATION`, one day after an all-day `DTSTART`, or the `DTSTART`
+     * itself for a date-time.
+     *
+     * See [3.6.1. Event Component](https://datatracker.ietf.org/doc/html/rfc5545#section-3.6.1)
+     */
+    #[must_use]
+    pub fn end(&self) -> crate::Date {
+        if let Some(dtend) = &self.dtend {
+            return dtend.clone();
+        }
+
+        if let Some(duration) = self.duration {
+            return self.dtstart.clone() + duration;
+        }
+
+        match &self.dtstart {
+            crate::Date::Date(_) => self.dtstart.clone() + crate::Duration::days(1),
+            crate::Date::DateTime(_) => self.dtstart.clone(),
+        }
+    }
+
+    /**
+     * Same as [`Self::end`], but the `DURATION` elapses in the `vtimezone`
+     * of a zoned `DTSTART`, see [`crate::DateTime::add_in`].
+     */
+    #[must_use]
+    pub fn end_in(&self, vtimezone: &crate::VTimezone) -> crate::Date {
+        match (&self.dtend, self.duration) {
+            (None, Some(duration)) => self.dtstart.clone().add_in(duration, vtimezone),
+            _ => self.end(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -98,6 +139,87 @@ END:VEVENT\r
         Ok(())
     }
 
+    #[test]
+    fn ser_time_zones() -> crate::Result {
+        let ical = "BEGIN:VEVENT\r
+DTSTAMP:20170209T192358Z\r
+UID:5UILHLI7RI6K2IDRAQX7O\r
+DTSTART;TZID=Europe/Paris:20170209T100000\r
+RECURRENCE-ID;TZID=Europe/Paris:20170209T100000\r
+RRULE:FREQ=DAILY;COUNT=4;INTERVAL=1\r
+DTEND;TZID=Europe/Paris:20170209T110000\r
+EXDATE;TZID=Europe/Paris:20170210T100000\r
+EXDATE;TZID=Europe/Paris:20170211T100000\r
+EXDATE:20170212T090000Z\r
+RDATE;VALUE=PERIOD;TZID=Europe/Paris:20170215T100000/PT3600S\r
+END:VEVENT\r
+";
+        let vevent = crate::VEvent::try_from(ical)?;
+
+        assert_eq!(vevent.dtstart.tzid(), Some("Europe/Paris"));
+        assert_eq!(vevent.exdate[2].tzid(), None);
+        similar_asserts::assert_eq!(crate::ser::ical(&vevent)?, ical);
+
+        Ok(())
+    }
+
+    #[test]
+    fn unicode_round_trip() -> crate::Result {
+        let input = std::fs::read_to_string("tests/events/event_with_unicode_fields.ics").unwrap();
+        let vevent = crate::VEvent::try_from(input)?;
+
+        let ical = crate::ser::ical(&vevent)?;
+        similar_asserts::assert_eq!(crate::VEvent::try_from(ical.as_str())?, vevent);
+
+        let mut vevent = vevent;
+        vevent.summary = Some(vevent.summary.unwrap().repeat(5).into());
+        vevent.description = Some("Événement de l’équipe à Tōkyō 東京".repeat(7).into());
+
+        let ical = crate::ser::ical(&vevent)?;
+        for line in ical.split("\r\n") {
+            assert!(line.len() <= 75, "{line}");
+        }
+        similar_asserts::assert_eq!(crate::VEvent::try_from(ical.as_str())?, vevent);
+
+        Ok(())
+    }
+
+    #[test]
+    fn escaped_text() -> crate::Result {
+        let input = "BEGIN:VEVENT\r
+UID:1\r
+DTSTAMP:20240101T000000Z\r
+DTSTART:20240101T090000\r
+SUMMARY:Lunch\\; then a walk\\, maybe\r
+DESCRIPTION:C:\\\\Users\\\\ikal\\nSecond line\\NThird line\r
+CATEGORIES:Food\\, drinks,Outdoor\r
+RESOURCES:Table\\;chairs\r
+END:VEVENT\r
+";
+        let vevent = crate::VEvent::try_from(input)?;
+
+        assert_eq!(vevent.summary.as_deref(), Some("Lunch; then a walk, maybe"));
+        assert_eq!(
+            vevent.description.as_deref(),
+            Some("C:\\Users\\ikal\nSecond line\nThird line")
+        );
+        assert_eq!(
+            vevent.categories.iter().map(|x| &**x).collect::<Vec<_>>(),
+            ["Food, drinks", "Outdoor"]
+        );
+        assert_eq!(
+            vevent.resources.iter().map(|x| &**x).collect::<Vec<_>>(),
+            ["Table;chairs"]
+        );
+
+        let ical = crate::ser::ical(&vevent)?;
+        assert!(ical.contains("DESCRIPTION:C:\\\\Users\\\\ikal\\nSecond line\\nThird line\r\n"));
+        assert!(ical.contains("CATEGORIES:Food\\, drinks,Outdoor\r\n"));
+        similar_asserts::assert_eq!(crate::VEvent::try_from(ical.as_str())?, vevent);
+
+        Ok(())
+    }
+
     #[test]
     fn macros() -> crate::Result {
         let _vevent = crate::vevent! {
@@ -125,7 +247,7 @@ END:VEVENT\r
                 interval: 1,
             },
             dtend: "20170210",
-            duration: "P1Y",
+            duration: "P1W",
             attach: [""],
             attendee: [""],
             categories: [""],
@@ -150,4 +272,67 @@ END:VEVENT\r
 
         Ok(())
     }
+
+    #[test]
+    fn end() -> crate::Result {
+        let mut vevent = crate::vevent! {
+            dtstart: "20240330",
+        }?;
+        assert_eq!(vevent.end(), "20240331".parse()?);
+
+        vevent.dtstart = "20240330T120000".parse()?;
+        assert_eq!(vevent.end(), "20240330T120000".parse()?);
+
+        vevent.duration = Some("P1DT1H".parse()?);
+        assert_eq!(vevent.end(), "20240331T130000".parse()?);
+
+        vevent.dtend = Some("20240330T140000".parse()?);
+        assert_eq!(vevent.end(), "20240330T140000".parse()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn end_in() -> crate::Result {
+        let vtimezone = crate::VTimezone::try_from(
+            "BEGIN:VTIMEZONE\r
+TZID:Europe/Paris\r
+BEGIN:DAYLIGHT\r
+DTSTART:19810329T020000\r
+RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU\r
+TZOFFSETFROM:+0100\r
+TZOFFSETTO:+0200\r
+END:DAYLIGHT\r
+BEGIN:STANDARD\r
+DTSTART:19961027T030000\r
+RRULE:FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU\r
+TZOFFSETFROM:+0200\r
+TZOFFSETTO:+0100\r
+END:STANDARD\r
+END:VTIMEZONE\r
+",
+        )?;
+        let zoned = |s: &str| -> crate::Result<crate::Date> {
+            let naive = s.parse::<crate::DateTime>()?.naive();
+
+            Ok(crate::DateTime::Zoned(naive, "Europe/Paris".to_string()).into())
+        };
+
+        let mut vevent = crate::vevent! {
+            dtstart: "20240330T120000",
+            duration: "PT24H",
+        }?;
+        vevent.dtstart = zoned("20240330T120000")?;
+        assert_eq!(vevent.end_in(&vtimezone), zoned("20240331T130000")?);
+        assert_eq!(vevent.end(), zoned("20240331T120000")?);
+
+        vevent.duration = Some("P1D".parse()?);
+        assert_eq!(vevent.end_in(&vtimezone), zoned("20240331T120000")?);
+
+        vevent.dtstart = "20240330T120000".parse()?;
+        vevent.duration = Some("PT24H".parse()?);
+        assert_eq!(vevent.end_in(&vtimezone), "20240331T120000".parse()?);
+
+        Ok(())
+    }
 }
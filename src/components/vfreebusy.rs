@@ -2,23 +2,27 @@
  * See [3.6.4. Free/Busy Component](https://datatracker.ietf.org/doc/html/rfc5545#section-3.6.4)
  */
 #[derive(Clone, Debug, Default, PartialEq, crate::Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VFreebusy {
     pub dtstamp: crate::DateTime,
     pub uid: crate::Text,
     pub contact: Option<crate::Text>,
     pub dtstart: Option<crate::Date>,
     pub dtend: Option<crate::Date>,
-    pub organizer: Option<crate::Uri>,
+    pub organizer: Option<crate::CalAddress>,
     pub url: Option<crate::Uri>,
-    pub attendee: Vec<crate::Uri>,
+    pub attendee: Vec<crate::CalAddress>,
     pub comment: Vec<crate::Text>,
-    #[component(append)]
-    pub freebusy: Vec<crate::Period>,
+    pub freebusy: Vec<crate::FreeBusy>,
     pub rstatus: Vec<crate::RequestStatus>,
     #[component(ignore)]
     pub x_prop: std::collections::BTreeMap<String, crate::ContentLine>,
     #[component(ignore)]
     pub iana_prop: std::collections::BTreeMap<String, crate::ContentLine>,
+    #[component(ignore)]
+    pub x_comp: Vec<crate::RawComponent>,
+    #[component(ignore)]
+    pub iana_comp: Vec<crate::RawComponent>,
 }
 impl VFreebusy {
     #[must_use]
@@ -27,6 +31,24 @@ impl VFreebusy {
     }
 }
 
+/**
+ * Sorts the intervals and merges the overlapping or adjacent ones.
+ */
+pub(crate) fn merge<T: Copy + Ord>(mut intervals: Vec<(T, T)>) -> Vec<(T, T)> {
+    intervals.sort();
+
+    let mut merged = Vec::<(T, T)>::new();
+
+    for (start, end) in intervals {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+
+    merged
+}
+
 #[cfg(test)]
 mod test {
     #[test]
@@ -2,6 +2,7 @@
  * See [3.6.3. Journal Component](https://datatracker.ietf.org/doc/html/rfc5545#section-3.6.3)
  */
 #[derive(Clone, Debug, Default, PartialEq, crate::Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VJournal {
     pub dtstamp: crate::DateTime,
     pub uid: crate::Text,
@@ -9,15 +10,16 @@ pub struct VJournal {
     pub created: Option<crate::DateTime>,
     pub dtstart: crate::Date,
     pub last_modified: Option<crate::DateTime>,
-    pub organizer: Option<crate::Uri>,
-    pub recurid: Option<crate::Date>,
+    pub organizer: Option<crate::CalAddress>,
+    #[component(rename = "RECURRENCE-ID")]
+    pub recurid: Option<crate::RecurId>,
     pub sequence: Option<u32>,
     pub status: Option<crate::Status>,
     pub summary: Option<crate::Text>,
     pub url: Option<crate::Uri>,
     pub rrule: Option<crate::Recur>,
-    pub attach: Vec<crate::Text>,
-    pub attendee: Vec<crate::Uri>,
+    pub attach: Vec<crate::Attachment>,
+    pub attendee: Vec<crate::CalAddress>,
     #[component(append)]
     pub categories: Vec<crate::Text>,
     pub comment: Vec<crate::Text>,
@@ -32,6 +34,10 @@ pub struct VJournal {
     pub x_prop: std::collections::BTreeMap<String, crate::ContentLine>,
     #[component(ignore)]
     pub iana_prop: std::collections::BTreeMap<String, crate::ContentLine>,
+    #[component(ignore)]
+    pub x_comp: Vec<crate::RawComponent>,
+    #[component(ignore)]
+    pub iana_comp: Vec<crate::RawComponent>,
 }
 
 impl VJournal {
@@ -75,12 +81,12 @@ UID:19970901T130000Z-123405@example.com\r
 DTSTART;VALUE=DATE:19970317\r
 SUMMARY:Staff meeting minutes\r
 DESCRIPTION:1. Staff meeting: Participants include Joe\\, Lisa\\, and Bob. Au\r
- rora project plans were reviewed. There is currently no budget reserves for\r
-  this project. Lisa will escalate to management. Next meeting on Tuesday.\\n\r
- 2. Telephone Conference: ABC Corp. sales representative called to discuss n\r
- ew printer. Promised to get us a demo by Friday.\\n3. Henry Miller (Handsoff\r
-  Insurance): Car was totaled by tree. Is looking into a loaner car. 555-232\r
- 3 (tel).\r
+ rora project plans were reviewed. There is currently no budget reserves fo\r
+ r this project. Lisa will escalate to management. Next meeting on Tuesday.\r
+ \\n2. Telephone Conference: ABC Corp. sales representative called to discus\r
+ s new printer. Promised to get us a demo by Friday.\\n3. Henry Miller (Hand\r
+ soff Insurance): Car was totaled by tree. Is looking into a loaner car. 55\r
+ 5-2323 (tel).\r
 END:VJOURNAL\r
 "
         );
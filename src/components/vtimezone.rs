@@ -1,9 +1,12 @@
+mod zoneinfo;
+
 use std::collections::BTreeMap;
 
 /**
  * See [3.6.5. Time Zone Component](https://datatracker.ietf.org/doc/html/rfc5545#section-3.6.5)
  */
 #[derive(Clone, Debug, Default, Eq, PartialEq, crate::Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VTimezone {
     pub tzid: crate::Text,
     pub last_modified: Option<crate::DateTime>,
@@ -16,6 +19,10 @@ pub struct VTimezone {
     pub x_prop: std::collections::BTreeMap<String, crate::ContentLine>,
     #[component(ignore)]
     pub iana_prop: std::collections::BTreeMap<String, crate::ContentLine>,
+    #[component(ignore)]
+    pub x_comp: Vec<crate::RawComponent>,
+    #[component(ignore)]
+    pub iana_comp: Vec<crate::RawComponent>,
 }
 
 impl VTimezone {
@@ -23,20 +30,158 @@ impl VTimezone {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /**
+     * Returns the UTC offset in effect at the `utc` instant, `None` if the
+     * time zone has no observance.
+     *
+     * Before the first onset, the `TZOFFSETFROM` of the earliest observance
+     * applies.
+     */
+    #[must_use]
+    pub fn offset_at_utc(&self, utc: chrono::NaiveDateTime) -> Option<chrono::FixedOffset> {
+        if let Some(transition) = self.last_transition(utc) {
+            return Some(transition.to);
+        }
+
+        self.transitions(utc)
+            .into_iter()
+            .filter_map(|mut transitions| transitions.next())
+            .min_by_key(|x| x.utc)
+            .map(|x| x.from)
+    }
+
+    /**
+     * Returns the UTC offset of the `local` wall time.
+     *
+     * The result is `LocalResult::None` for a time skipped by a transition
+     * and `LocalResult::Ambiguous`, earliest instant first, for a time
+     * repeated by a transition.
+     */
+    #[must_use]
+    pub fn offset_at_local(
+        &self,
+        local: chrono::NaiveDateTime,
+    ) -> chrono::LocalResult<chrono::FixedOffset> {
+        let mut offsets = self
+            .standard
+            .iter()
+            .flat_map(|x| [x.tzoffsetfrom, x.tzoffsetto])
+            .chain(
+                self.daylight
+                    .iter()
+                    .flat_map(|x| [x.tzoffsetfrom, x.tzoffsetto]),
+            )
+            .collect::<Vec<_>>();
+        offsets.sort_by_key(|x| std::cmp::Reverse(x.local_minus_utc()));
+        offsets.dedup();
+
+        let offsets = offsets
+            .into_iter()
+            .filter(|offset| self.offset_at_utc(local - *offset) == Some(*offset))
+            .collect::<Vec<_>>();
+
+        match offsets.as_slice() {
+            [] => chrono::LocalResult::None,
+            [offset] => chrono::LocalResult::Single(*offset),
+            [first, .., last] => chrono::LocalResult::Ambiguous(*first, *last),
+        }
+    }
+
+    /**
+     * Converts the `local` wall time to an absolute instant.
+     *
+     * As defined by [3.3.5. Date-Time](https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.5),
+     * a repeated time refers to its first occurrence and a skipped time is
+     * interpreted using the offset before the gap.
+     */
+    #[must_use]
+    pub fn to_utc(&self, local: chrono::NaiveDateTime) -> Option<chrono::DateTime<chrono::Utc>> {
+        let offset = match self.offset_at_local(local) {
+            chrono::LocalResult::Single(offset) | chrono::LocalResult::Ambiguous(offset, _) => {
+                offset
+            }
+            chrono::LocalResult::None => self.offset_at_utc(local - chrono::TimeDelta::days(1))?,
+        };
+
+        Some((local - offset).and_utc())
+    }
+
+    /**
+     * Same as [`Self::to_utc`] but a skipped time is moved to the end of the
+     * gap, the instant of the transition.
+     */
+    pub(crate) fn to_utc_clamped(
+        &self,
+        local: chrono::NaiveDateTime,
+    ) -> Option<chrono::DateTime<chrono::Utc>> {
+        let utc = self.to_utc(local)?;
+
+        if self.offset_at_local(local) == chrono::LocalResult::None {
+            self.last_transition(utc.naive_utc())
+                .map(|x| x.utc.and_utc())
+        } else {
+            Some(utc)
+        }
+    }
+
+    /**
+     * Returns the latest onset at or before the `utc` instant.
+     */
+    fn last_transition(&self, utc: chrono::NaiveDateTime) -> Option<Transition> {
+        self.transitions(utc)
+            .into_iter()
+            .filter_map(|transitions| transitions.take_while(|x| x.utc <= utc).last())
+            .min_by_key(|x| std::cmp::Reverse(x.utc))
+    }
+
+    /**
+     * Transitions of each observance around the `utc` instant, in
+     * chronological order.
+     */
+    fn transitions(
+        &self,
+        utc: chrono::NaiveDateTime,
+    ) -> Vec<Box<dyn Iterator<Item = Transition> + '_>> {
+        let standard = self
+            .standard
+            .iter()
+            .map(|x| Box::new(x.transitions(utc)) as Box<dyn Iterator<Item = Transition>>);
+        let daylight = self
+            .daylight
+            .iter()
+            .map(|x| Box::new(x.transitions(utc)) as Box<dyn Iterator<Item = Transition>>);
+
+        standard.chain(daylight).collect()
+    }
+}
+
+/**
+ * Onset of an observance.
+ */
+#[derive(Clone, Debug)]
+struct Transition {
+    utc: chrono::NaiveDateTime,
+    from: chrono::FixedOffset,
+    to: chrono::FixedOffset,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) enum Component {
     Standard(Standard),
     Daylight(Daylight),
+    Raw(crate::RawComponent),
 }
 
 macro_rules! prop {
     ($name:ident) => {
         #[derive(Clone, Debug, Eq, PartialEq, crate::Component)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct $name {
             pub dtstart: crate::Date,
+            #[cfg_attr(feature = "serde", serde(with = "crate::ser::serde::fixed_offset"))]
             pub tzoffsetto: chrono::offset::FixedOffset,
+            #[cfg_attr(feature = "serde", serde(with = "crate::ser::serde::fixed_offset"))]
             pub tzoffsetfrom: chrono::offset::FixedOffset,
             pub rrule: Option<crate::Recur>,
             pub comment: Vec<crate::Text>,
@@ -46,6 +191,10 @@ macro_rules! prop {
             pub x_prop: BTreeMap<String, crate::ContentLine>,
             #[component(ignore)]
             pub iana_prop: BTreeMap<String, crate::ContentLine>,
+            #[component(ignore)]
+            pub x_comp: Vec<crate::RawComponent>,
+            #[component(ignore)]
+            pub iana_comp: Vec<crate::RawComponent>,
         }
 
         impl Default for $name {
@@ -55,6 +204,57 @@ macro_rules! prop {
         }
 
         impl $name {
+            #[must_use]
+            pub fn recurrent(&self) -> crate::iter::Recur<Self> {
+                crate::iter::Recur::from(self)
+            }
+
+            /**
+             * The onsets are expressed in the local time in effect before
+             * them.
+             *
+             * An endless yearly rule gives the same onsets whatever the
+             * year it starts, so the expansion starts two years before `utc`
+             * instead of the `DTSTART`, often in 1601 for Outlook. Only the
+             * onsets since the year before `utc` are kept, the new
+             * `DTSTART` may not follow the rule.
+             */
+            fn transitions(&self, utc: chrono::NaiveDateTime) -> impl Iterator<Item = Transition> {
+                use chrono::Datelike as _;
+
+                let from = self.tzoffsetfrom;
+                let to = self.tzoffsetto;
+
+                let mut observance = self.clone();
+                let mut since = None;
+
+                if let Some(rrule) = &self.rrule
+                    && rrule.freq == crate::Freq::Yearly
+                    && rrule.interval <= 1
+                    && rrule.count.is_none()
+                    && rrule.until.is_none()
+                    && let crate::Date::DateTime(crate::DateTime::Naive(dtstart)) = self.dtstart
+                    && (dtstart.month(), dtstart.day()) != (2, 29)
+                    && let Some(years) = u32::try_from(utc.year() - 2 - dtstart.year())
+                        .ok()
+                        .filter(|x| *x > 0)
+                    && let Some(shifted) = dtstart.checked_add_months(chrono::Months::new(12 * years))
+                {
+                    observance.dtstart = crate::DateTime::Naive(shifted).into();
+                    since = chrono::NaiveDate::from_ymd_opt(utc.year() - 1, 1, 1)
+                        .map(|x| x.and_time(chrono::NaiveTime::MIN));
+                }
+
+                observance
+                    .recurrent()
+                    .skip_while(move |x| since.is_some_and(|since| x.dtstart.naive() < since))
+                    .map(move |x| Transition {
+                        utc: x.dtstart.naive() - from,
+                        from,
+                        to,
+                    })
+            }
+
             #[must_use]
             fn new() -> Self {
                 Self {
@@ -67,9 +267,45 @@ macro_rules! prop {
                     tzname: Vec::new(),
                     x_prop: BTreeMap::new(),
                     iana_prop: BTreeMap::new(),
+                    x_comp: Vec::new(),
+                    iana_comp: Vec::new(),
                 }
             }
         }
+
+        impl crate::iter::Recurring for $name {
+            fn dtstart(&self) -> Option<&crate::Date> {
+                Some(&self.dtstart)
+            }
+
+            fn exdate(&self) -> &[crate::Date] {
+                &[]
+            }
+
+            fn set_dtstart(&mut self, dtstart: crate::Date) {
+                self.dtstart = dtstart;
+            }
+
+            fn rdate(&self) -> &[crate::RDate] {
+                &self.rdate
+            }
+
+            fn rrule(&self) -> Option<&crate::Recur> {
+                self.rrule.as_ref()
+            }
+
+            fn set_rrule(&mut self, rrule: crate::Recur) {
+                self.rrule = Some(rrule);
+            }
+
+            /**
+             * A UTC `UNTIL` is compared with the onset shifted by
+             * `TZOFFSETFROM`.
+             */
+            fn offset(&self, _local: chrono::NaiveDateTime) -> Option<chrono::FixedOffset> {
+                Some(self.tzoffsetfrom)
+            }
+        }
     }
 }
 
@@ -83,6 +319,115 @@ mod test {
         crate::test::test_files::<crate::VTimezone>("timezones");
     }
 
+    #[test]
+    fn to_utc() -> crate::Result {
+        let vtimezone = crate::VTimezone::try_from(
+            "BEGIN:VTIMEZONE\r
+TZID:America/New_York\r
+BEGIN:DAYLIGHT\r
+DTSTART:20070311T020000\r
+RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=2SU\r
+TZOFFSETFROM:-0500\r
+TZOFFSETTO:-0400\r
+TZNAME:EDT\r
+END:DAYLIGHT\r
+BEGIN:STANDARD\r
+DTSTART:20071104T020000\r
+RRULE:FREQ=YEARLY;BYMONTH=11;BYDAY=1SU\r
+TZOFFSETFROM:-0400\r
+TZOFFSETTO:-0500\r
+TZNAME:EST\r
+END:STANDARD\r
+END:VTIMEZONE\r
+",
+        )?;
+
+        let edt = chrono::FixedOffset::west_opt(4 * 3600).unwrap();
+        let est = chrono::FixedOffset::west_opt(5 * 3600).unwrap();
+        let naive = |s: &str| chrono::NaiveDateTime::parse_from_str(s, "%Y%m%dT%H%M%S").unwrap();
+        let utc = |s: &str| Some(naive(s).and_utc());
+
+        assert_eq!(
+            vtimezone.to_utc(naive("20240115T120000")),
+            utc("20240115T170000")
+        );
+        assert_eq!(
+            vtimezone.to_utc(naive("20240701T120000")),
+            utc("20240701T160000")
+        );
+        // before the first onset
+        assert_eq!(
+            vtimezone.to_utc(naive("20000101T120000")),
+            utc("20000101T170000")
+        );
+
+        assert_eq!(vtimezone.offset_at_utc(naive("20240310T065959")), Some(est));
+        assert_eq!(vtimezone.offset_at_utc(naive("20240310T070000")), Some(edt));
+
+        // gap
+        assert_eq!(
+            vtimezone.offset_at_local(naive("20240310T023000")),
+            chrono::LocalResult::None
+        );
+        assert_eq!(
+            vtimezone.to_utc(naive("20240310T023000")),
+            utc("20240310T073000")
+        );
+
+        // overlap
+        assert_eq!(
+            vtimezone.offset_at_local(naive("20241103T013000")),
+            chrono::LocalResult::Ambiguous(edt, est)
+        );
+        assert_eq!(
+            vtimezone.to_utc(naive("20241103T013000")),
+            utc("20241103T053000")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn outlook() -> crate::Result {
+        let vtimezone = crate::VTimezone::try_from(
+            "BEGIN:VTIMEZONE\r
+TZID:W. Europe Standard Time\r
+BEGIN:STANDARD\r
+DTSTART:16010101T030000\r
+TZOFFSETFROM:+0200\r
+TZOFFSETTO:+0100\r
+RRULE:FREQ=YEARLY;BYDAY=-1SU;BYMONTH=10\r
+END:STANDARD\r
+BEGIN:DAYLIGHT\r
+DTSTART:16010101T020000\r
+TZOFFSETFROM:+0100\r
+TZOFFSETTO:+0200\r
+RRULE:FREQ=YEARLY;BYDAY=-1SU;BYMONTH=3\r
+END:DAYLIGHT\r
+END:VTIMEZONE\r
+",
+        )?;
+
+        let cest = chrono::FixedOffset::east_opt(2 * 3600);
+        let cet = chrono::FixedOffset::east_opt(3600);
+        let naive = |s: &str| chrono::NaiveDateTime::parse_from_str(s, "%Y%m%dT%H%M%S").unwrap();
+
+        assert_eq!(vtimezone.offset_at_utc(naive("16010601T120000")), cest);
+        assert_eq!(vtimezone.offset_at_utc(naive("16011201T120000")), cet);
+        assert_eq!(vtimezone.offset_at_utc(naive("20240101T003000")), cet);
+        assert_eq!(vtimezone.offset_at_utc(naive("20240331T005959")), cet);
+        assert_eq!(vtimezone.offset_at_utc(naive("20240331T010000")), cest);
+        assert_eq!(vtimezone.offset_at_utc(naive("20241027T005959")), cest);
+        assert_eq!(vtimezone.offset_at_utc(naive("20241027T010000")), cet);
+        assert_eq!(vtimezone.offset_at_utc(naive("20241231T233000")), cet);
+        assert_eq!(
+            vtimezone.to_utc(naive("20240701T120000")),
+            Some(naive("20240701T100000").and_utc())
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn ser() -> crate::Result {
         let vtimezone = crate::vtimezone! {
@@ -133,4 +478,33 @@ END:VTIMEZONE\r
 
         Ok(())
     }
+
+    #[test]
+    fn until_utc() -> crate::Result {
+        let vtimezone = crate::VTimezone::try_from(
+            "BEGIN:VTIMEZONE\r
+TZID:Europe/Paris\r
+BEGIN:STANDARD\r
+DTSTART:19961027T030000\r
+RRULE:FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU;UNTIL=19981025T010000Z\r
+TZOFFSETFROM:+0200\r
+TZOFFSETTO:+0100\r
+END:STANDARD\r
+END:VTIMEZONE\r
+",
+        )?;
+
+        // the last onset, 1998-10-25 03:00 local time, is 01:00 UTC
+        let onsets = vtimezone.standard[0]
+            .recurrent()
+            .map(|x| x.dtstart.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            onsets,
+            ["19961027T030000", "19971026T030000", "19981025T030000"]
+        );
+
+        Ok(())
+    }
 }
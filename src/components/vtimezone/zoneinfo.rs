@@ -0,0 +1,450 @@
+/*!
+ * Generates time zone definitions from the system
+ * [zoneinfo](https://www.iana.org/time-zones) database.
+ */
+
+use chrono::Datelike as _;
+
+const ZONEINFO: &str = "/usr/share/zoneinfo";
+const DAY: i64 = 86_400;
+/** Larger than any TZif file of the IANA database. */
+const MAX_SIZE: u64 = 1 << 20;
+
+#[derive(Clone, Debug, PartialEq)]
+struct LocalTimeType {
+    offset: chrono::FixedOffset,
+    dst: bool,
+    name: String,
+}
+
+/**
+ * A change of local time type, the onset being expressed in the local time
+ * in effect before it.
+ */
+#[derive(Clone, Debug)]
+struct Transition {
+    onset: chrono::NaiveDateTime,
+    from: chrono::FixedOffset,
+    to: LocalTimeType,
+}
+
+/**
+ * Transitions occurring the same day of each consecutive year, as the nth
+ * or the last weekday of the month.
+ */
+#[derive(Debug)]
+struct Run {
+    onsets: Vec<chrono::NaiveDateTime>,
+    nth: bool,
+    last: bool,
+}
+
+impl Run {
+    fn new(onset: chrono::NaiveDateTime) -> Self {
+        Self {
+            onsets: vec![onset],
+            nth: true,
+            last: is_last(onset.date()),
+        }
+    }
+
+    /**
+     * Adds `onset` if it follows the same yearly rule.
+     */
+    fn push(&mut self, onset: chrono::NaiveDateTime) -> bool {
+        let first = self.onsets[0];
+        let last = self.onsets[self.onsets.len() - 1];
+
+        let nth = self.nth && nth(onset.date()) == nth(first.date());
+        let is_last = self.last && is_last(onset.date());
+
+        if onset.year() != last.year() + 1
+            || onset.month() != last.month()
+            || onset.weekday() != last.weekday()
+            || onset.time() != last.time()
+            || !(nth || is_last)
+            || self.onsets.len() >= u8::MAX as usize
+        {
+            return false;
+        }
+
+        self.nth = nth;
+        self.last = is_last;
+        self.onsets.push(onset);
+
+        true
+    }
+
+    fn rrule(&self, ongoing: bool) -> Option<crate::Recur> {
+        let first = self.onsets[0];
+
+        if self.onsets.len() == 1 {
+            return None;
+        }
+
+        Some(crate::Recur {
+            freq: crate::Freq::Yearly,
+            count: if ongoing {
+                None
+            } else {
+                Some(self.onsets.len() as u8)
+            },
+            interval: 1,
+            by_month: vec![first.month() as i8],
+            by_day: vec![crate::WeekdayNum {
+                weekday: first.weekday().into(),
+                ord: Some(if self.last { -1 } else { nth(first.date()) }),
+            }],
+            ..Default::default()
+        })
+    }
+}
+
+impl super::VTimezone {
+    /**
+     * Generates the definition of the `tzid` IANA time zone for the `years`
+     * range from the zoneinfo files.
+     *
+     * The files are read from the `TZDIR` environment variable directory,
+     * `/usr/share/zoneinfo` by default. The transitions occurring the same
+     * day of each year are described by a `RRULE`, those of the last year of
+     * the range are supposed to continue after it.
+     *
+     * Only the relative IANA names are accepted, the `tzid` usually comes
+     * from an untrusted calendar.
+     */
+    pub fn from_zoneinfo(tzid: &str, years: std::ops::RangeInclusive<i32>) -> crate::Result<Self> {
+        let dir = std::env::var("TZDIR").unwrap_or_else(|_| ZONEINFO.to_string());
+        let data = read(&dir, tzid).ok_or_else(|| crate::Error::Timezone(tzid.to_string()))?;
+        let tz = tz::TimeZone::from_tz_data(&data)
+            .map_err(|err| crate::Error::Zoneinfo(format!("{tzid}: {err}")))?;
+
+        let start = timestamp(*years.start())?;
+        let end = timestamp(years.end() + 1)?;
+
+        let mut vtimezone = Self {
+            tzid: tzid.into(),
+            ..Default::default()
+        };
+
+        let transitions = transitions(&tz, start, end)?;
+
+        if transitions.is_empty() {
+            let current = local_time_type(&tz, start)?;
+            let onset = utc(start) + current.offset;
+
+            push(&mut vtimezone, &current, current.offset, onset, None);
+
+            return Ok(vtimezone);
+        }
+
+        let mut groups = Vec::<(chrono::FixedOffset, LocalTimeType, Vec<Run>)>::new();
+
+        for transition in transitions {
+            let index = match groups
+                .iter()
+                .position(|(from, to, _)| *from == transition.from && *to == transition.to)
+            {
+                Some(index) => index,
+                None => {
+                    groups.push((transition.from, transition.to, Vec::new()));
+                    groups.len() - 1
+                }
+            };
+
+            let runs = &mut groups[index].2;
+
+            if !runs.last_mut().is_some_and(|x| x.push(transition.onset)) {
+                runs.push(Run::new(transition.onset));
+            }
+        }
+
+        for (from, to, runs) in groups {
+            for run in runs {
+                let ongoing = run.onsets.last().map(|x| x.year()) == Some(*years.end());
+
+                push(&mut vtimezone, &to, from, run.onsets[0], run.rrule(ongoing));
+            }
+        }
+
+        vtimezone.standard.sort_by_key(|x| x.dtstart.naive());
+        vtimezone.daylight.sort_by_key(|x| x.dtstart.naive());
+
+        Ok(vtimezone)
+    }
+}
+
+/**
+ * Reads the zoneinfo file of `tzid`, a regular file of at most
+ * [`MAX_SIZE`] bytes.
+ */
+fn read(dir: &str, tzid: &str) -> Option<Vec<u8>> {
+    use std::io::Read as _;
+
+    let valid = tzid.split('/').all(|segment| {
+        !matches!(segment, "" | "." | "..")
+            && segment
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '+' | '-'))
+    });
+
+    if !valid {
+        return None;
+    }
+
+    let file = std::fs::File::open(std::path::Path::new(dir).join(tzid)).ok()?;
+
+    if !file.metadata().ok()?.is_file() {
+        return None;
+    }
+
+    let mut data = Vec::new();
+    file.take(MAX_SIZE + 1).read_to_end(&mut data).ok()?;
+
+    if data.len() as u64 > MAX_SIZE {
+        return None;
+    }
+
+    Some(data)
+}
+
+fn push(
+    vtimezone: &mut super::VTimezone,
+    to: &LocalTimeType,
+    from: chrono::FixedOffset,
+    onset: chrono::NaiveDateTime,
+    rrule: Option<crate::Recur>,
+) {
+    macro_rules! observance {
+        ($ty:ident) => {
+            super::$ty {
+                dtstart: onset.into(),
+                tzoffsetfrom: from,
+                tzoffsetto: to.offset,
+                rrule,
+                tzname: vec![to.name.as_str().into()],
+                ..Default::default()
+            }
+        };
+    }
+
+    if to.dst {
+        vtimezone.daylight.push(observance!(Daylight));
+    } else {
+        vtimezone.standard.push(observance!(Standard));
+    }
+}
+
+/**
+ * Transitions between the `start` and `end` timestamps, found by comparing
+ * the local time type of each day.
+ */
+fn transitions(tz: &tz::TimeZone, start: i64, end: i64) -> crate::Result<Vec<Transition>> {
+    let mut transitions = Vec::new();
+    let mut current = local_time_type(tz, start)?;
+    let mut day = start;
+
+    while day < end {
+        if local_time_type(tz, day + DAY)? == current {
+            day += DAY;
+            continue;
+        }
+
+        let (mut before, mut after) = (day, day + DAY);
+
+        while after - before > 1 {
+            let middle = before + (after - before) / 2;
+
+            if local_time_type(tz, middle)? == current {
+                before = middle;
+            } else {
+                after = middle;
+            }
+        }
+
+        let to = local_time_type(tz, after)?;
+
+        if after < end {
+            transitions.push(Transition {
+                onset: utc(after) + current.offset,
+                from: current.offset,
+                to: to.clone(),
+            });
+        }
+
+        current = to;
+        day = after;
+    }
+
+    Ok(transitions)
+}
+
+fn local_time_type(tz: &tz::TimeZone, timestamp: i64) -> crate::Result<LocalTimeType> {
+    let local_time_type = tz
+        .find_local_time_type(timestamp)
+        .map_err(|err| crate::Error::Zoneinfo(err.to_string()))?;
+    let offset = local_time_type.ut_offset();
+
+    Ok(LocalTimeType {
+        offset: chrono::FixedOffset::east_opt(offset)
+            .ok_or_else(|| crate::Error::Zoneinfo(format!("Invalid offset {offset}")))?,
+        dst: local_time_type.is_dst(),
+        name: local_time_type.time_zone_designation().to_string(),
+    })
+}
+
+fn timestamp(year: i32) -> crate::Result<i64> {
+    chrono::NaiveDate::from_ymd_opt(year, 1, 1)
+        .map(|x| x.and_time(chrono::NaiveTime::MIN).and_utc().timestamp())
+        .ok_or_else(|| crate::Error::Zoneinfo(format!("Invalid year {year}")))
+}
+
+fn utc(timestamp: i64) -> chrono::NaiveDateTime {
+    chrono::DateTime::from_timestamp(timestamp, 0)
+        .unwrap_or_default()
+        .naive_utc()
+}
+
+fn nth(date: chrono::NaiveDate) -> i8 {
+    date.day0() as i8 / 7 + 1
+}
+
+fn is_last(date: chrono::NaiveDate) -> bool {
+    (date + chrono::TimeDelta::days(7)).month() != date.month()
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn europe_paris() -> crate::Result {
+        let vtimezone = crate::VTimezone::from_zoneinfo("Europe/Paris", 2020..=2030)?;
+
+        similar_asserts::assert_eq!(
+            crate::ser::ical(&vtimezone)?,
+            "BEGIN:VTIMEZONE\r
+TZID:Europe/Paris\r
+BEGIN:STANDARD\r
+DTSTART:20201025T030000\r
+TZOFFSETTO:+0100\r
+TZOFFSETFROM:+0200\r
+RRULE:FREQ=YEARLY;INTERVAL=1;BYDAY=-1SU;BYMONTH=10\r
+TZNAME:CET\r
+END:STANDARD\r
+BEGIN:DAYLIGHT\r
+DTSTART:20200329T020000\r
+TZOFFSETTO:+0200\r
+TZOFFSETFROM:+0100\r
+RRULE:FREQ=YEARLY;INTERVAL=1;BYDAY=-1SU;BYMONTH=3\r
+TZNAME:CEST\r
+END:DAYLIGHT\r
+END:VTIMEZONE\r
+"
+        );
+
+        let naive = |s: &str| chrono::NaiveDateTime::parse_from_str(s, "%Y%m%dT%H%M%S").unwrap();
+        assert_eq!(
+            vtimezone.to_utc(naive("20450701T120000")),
+            Some(naive("20450701T100000").and_utc())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn rule_change() -> crate::Result {
+        let vtimezone = crate::VTimezone::from_zoneinfo("America/New_York", 2005..=2008)?;
+
+        similar_asserts::assert_eq!(
+            crate::ser::ical(&vtimezone)?,
+            "BEGIN:VTIMEZONE\r
+TZID:America/New_York\r
+BEGIN:STANDARD\r
+DTSTART:20051030T020000\r
+TZOFFSETTO:-0500\r
+TZOFFSETFROM:-0400\r
+RRULE:FREQ=YEARLY;COUNT=2;INTERVAL=1;BYDAY=-1SU;BYMONTH=10\r
+TZNAME:EST\r
+END:STANDARD\r
+BEGIN:STANDARD\r
+DTSTART:20071104T020000\r
+TZOFFSETTO:-0500\r
+TZOFFSETFROM:-0400\r
+RRULE:FREQ=YEARLY;INTERVAL=1;BYDAY=1SU;BYMONTH=11\r
+TZNAME:EST\r
+END:STANDARD\r
+BEGIN:DAYLIGHT\r
+DTSTART:20050403T020000\r
+TZOFFSETTO:-0400\r
+TZOFFSETFROM:-0500\r
+RRULE:FREQ=YEARLY;COUNT=2;INTERVAL=1;BYDAY=1SU;BYMONTH=4\r
+TZNAME:EDT\r
+END:DAYLIGHT\r
+BEGIN:DAYLIGHT\r
+DTSTART:20070311T020000\r
+TZOFFSETTO:-0400\r
+TZOFFSETFROM:-0500\r
+RRULE:FREQ=YEARLY;INTERVAL=1;BYDAY=2SU;BYMONTH=3\r
+TZNAME:EDT\r
+END:DAYLIGHT\r
+END:VTIMEZONE\r
+"
+        );
+
+        let naive = |s: &str| chrono::NaiveDateTime::parse_from_str(s, "%Y%m%dT%H%M%S").unwrap();
+        let edt = chrono::FixedOffset::west_opt(4 * 3600);
+        let est = chrono::FixedOffset::west_opt(5 * 3600);
+
+        assert_eq!(vtimezone.offset_at_utc(naive("20060402T065959")), est);
+        assert_eq!(vtimezone.offset_at_utc(naive("20060402T070000")), edt);
+        assert_eq!(vtimezone.offset_at_utc(naive("20061029T055959")), edt);
+        assert_eq!(vtimezone.offset_at_utc(naive("20061029T060000")), est);
+        assert_eq!(vtimezone.offset_at_utc(naive("20070311T070000")), edt);
+        assert_eq!(vtimezone.offset_at_utc(naive("20071104T060000")), est);
+        assert_eq!(vtimezone.offset_at_utc(naive("20100701T000000")), edt);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fixed() -> crate::Result {
+        let vtimezone = crate::VTimezone::from_zoneinfo("Asia/Tokyo", 2020..=2030)?;
+
+        assert_eq!(vtimezone.standard.len(), 1);
+        assert!(vtimezone.daylight.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown() {
+        assert_eq!(
+            crate::VTimezone::from_zoneinfo("Europe/Unknown", 2020..=2030),
+            Err(crate::Error::Timezone("Europe/Unknown".to_string()))
+        );
+    }
+
+    #[test]
+    fn invalid_name() {
+        for tzid in [
+            "/etc/hostname",
+            "/proc/self/status",
+            "/dev/zero",
+            "../../../etc/hostname",
+            "Europe/../Europe/Paris",
+            "./Europe/Paris",
+            "Europe//Paris",
+            "Europe/Paris/",
+            "Europe",
+            "Europe/Paris\0",
+            "",
+        ] {
+            assert_eq!(
+                crate::VTimezone::from_zoneinfo(tzid, 2020..=2030),
+                Err(crate::Error::Timezone(tzid.to_string())),
+                "{tzid}"
+            );
+        }
+
+        assert!(crate::VTimezone::from_zoneinfo("Etc/GMT+5", 2020..=2030).is_ok());
+    }
+}
@@ -2,6 +2,7 @@
  * See [3.6.2. To-Do Component](https://datatracker.ietf.org/doc/html/rfc5545#section-3.6.2)
  */
 #[derive(Clone, Default, Debug, PartialEq, crate::Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VTodo {
     pub dtstamp: crate::DateTime,
     pub uid: crate::Text,
@@ -12,19 +13,20 @@ pub struct VTodo {
     pub geo: Option<crate::Geo>,
     pub last_modified: Option<crate::DateTime>,
     pub location: Option<crate::Text>,
-    pub organizer: Option<crate::Uri>,
+    pub organizer: Option<crate::CalAddress>,
     pub percent_complete: Option<u8>,
     pub priority: Option<u8>,
-    pub recurid: Option<crate::Date>,
+    #[component(rename = "RECURRENCE-ID")]
+    pub recurid: Option<crate::RecurId>,
     pub sequence: Option<u32>,
     pub status: Option<crate::Status>,
     pub summary: Option<crate::Text>,
     pub url: Option<crate::Uri>,
     pub rrule: Option<crate::Recur>,
     pub due: Option<crate::Date>,
-    pub duration: Option<chrono::Duration>,
-    pub attach: Vec<crate::Text>,
-    pub attendee: Vec<crate::Uri>,
+    pub duration: Option<crate::Duration>,
+    pub attach: Vec<crate::Attachment>,
+    pub attendee: Vec<crate::CalAddress>,
     #[component(append)]
     pub categories: Vec<crate::Text>,
     pub comment: Vec<crate::Text>,
@@ -40,6 +42,10 @@ pub struct VTodo {
     pub x_prop: std::collections::BTreeMap<String, crate::ContentLine>,
     #[component(ignore)]
     pub iana_prop: std::collections::BTreeMap<String, crate::ContentLine>,
+    #[component(ignore)]
+    pub x_comp: Vec<crate::RawComponent>,
+    #[component(ignore)]
+    pub iana_comp: Vec<crate::RawComponent>,
 }
 
 impl VTodo {
@@ -52,6 +58,37 @@ impl VTodo {
     pub fn recurrent(&self) -> crate::iter::Recur<Self> {
         crate::iter::Recur::from(self)
     }
+
+    /**
+     * The effective due of the to-do: the `DUE` or the `DTSTART` plus the
+     * `DURATION`, if any.
+     *
+     * See [3.6.2. To-Do Component](https://datatracker.ietf.org/doc/html/rfc5545#section-3.6.2)
+     */
+    #[must_use]
+    pub fn end(&self) -> Option<crate::Date> {
+        if let Some(due) = &self.due {
+            return Some(due.clone());
+        }
+
+        let dtstart = self.dtstart.clone()?;
+
+        self.duration.map(|duration| dtstart + duration)
+    }
+
+    /**
+     * Same as [`Self::end`], but the `DURATION` elapses in the `vtimezone`
+     * of a zoned `DTSTART`, see [`crate::DateTime::add_in`].
+     */
+    #[must_use]
+    pub fn end_in(&self, vtimezone: &crate::VTimezone) -> Option<crate::Date> {
+        match (&self.due, &self.dtstart, self.duration) {
+            (None, Some(dtstart), Some(duration)) => {
+                Some(dtstart.clone().add_in(duration, vtimezone))
+            }
+            _ => self.end(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -141,4 +178,20 @@ END:VTODO\r
 
         Ok(())
     }
+
+    #[test]
+    fn end() -> crate::Result {
+        let mut vtodo = crate::vtodo! {
+            dtstart: "20240330T120000",
+        }?;
+        assert_eq!(vtodo.end(), None);
+
+        vtodo.duration = Some("PT30M".parse()?);
+        assert_eq!(vtodo.end(), Some("20240330T123000".parse()?));
+
+        vtodo.due = Some("20240401".parse()?);
+        assert_eq!(vtodo.end(), Some("20240401".parse()?));
+
+        Ok(())
+    }
 }
@@ -5,9 +5,10 @@ use std::collections::BTreeMap;
  */
 
 #[derive(Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ContentLine {
     pub key: String,
-    pub params: BTreeMap<String, String>,
+    pub params: Params,
     pub value: String,
 }
 
@@ -26,7 +27,7 @@ impl ContentLine {
         Self {
             key: String::new(),
             value: value.to_string(),
-            params: BTreeMap::new(),
+            params: Params::new(),
         }
     }
 }
@@ -35,7 +36,7 @@ impl From<String> for ContentLine {
     fn from(value: String) -> Self {
         Self {
             key: String::new(),
-            params: BTreeMap::new(),
+            params: Params::new(),
             value,
         }
     }
@@ -53,9 +54,148 @@ impl std::fmt::Display for ContentLine {
     }
 }
 
+/**
+ * The parameters of a property, each one with a list of values.
+ *
+ * A repeated parameter is parsed as a single parameter with all the values.
+ *
+ * See [3.2. Property Parameters](https://datatracker.ietf.org/doc/html/rfc5545#section-3.2)
+ */
+#[derive(Clone, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Params(BTreeMap<String, Vec<String>>);
+
+impl Params {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /**
+     * Returns the first value of the `name` parameter.
+     */
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&String> {
+        self.get_all(name).first()
+    }
+
+    /**
+     * Returns all the values of the `name` parameter.
+     */
+    #[must_use]
+    pub fn get_all(&self, name: &str) -> &[String] {
+        self.0.get(name).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /**
+     * Replaces the values of the `name` parameter by `value`, returns the
+     * previous ones.
+     */
+    pub fn insert(&mut self, name: String, value: String) -> Option<Vec<String>> {
+        self.0.insert(name, vec![value])
+    }
+
+    /**
+     * Adds `value` to the values of the `name` parameter.
+     */
+    pub fn append(&mut self, name: String, value: String) {
+        self.0.entry(name).or_default().push(value);
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<Vec<String>> {
+        self.0.remove(name)
+    }
+
+    /**
+     * Takes the value of a single-valued parameter, it stays in the
+     * parameters if it has several values.
+     */
+    pub(crate) fn take_single(&mut self, name: &str) -> Option<String> {
+        match self.get_all(name) {
+            [value] => {
+                let value = value.clone();
+                self.remove(name);
+                Some(value)
+            }
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn contains_key(&self, name: &str) -> bool {
+        self.0.contains_key(name)
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn iter(&self) -> std::collections::btree_map::Iter<'_, String, Vec<String>> {
+        self.0.iter()
+    }
+}
+
+impl std::fmt::Debug for Params {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.0.iter()).finish()
+    }
+}
+
+impl FromIterator<(String, String)> for Params {
+    fn from_iter<I: IntoIterator<Item = (String, String)>>(iter: I) -> Self {
+        let mut params = Self::new();
+
+        for (name, value) in iter {
+            params.append(name, value);
+        }
+
+        params
+    }
+}
+
+impl<const N: usize> From<[(String, String); N]> for Params {
+    fn from(value: [(String, String); N]) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl<'a> IntoIterator for &'a Params {
+    type Item = (&'a String, &'a Vec<String>);
+    type IntoIter = std::collections::btree_map::Iter<'a, String, Vec<String>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl crate::ser::Serialize for Params {
+    fn ical(&self) -> crate::Result<String> {
+        let params = self
+            .iter()
+            .map(|(name, values)| {
+                let values = values
+                    .iter()
+                    .map(|x| crate::ser::param_value(x))
+                    .collect::<Vec<_>>();
+
+                format!("{name}={}", values.join(","))
+            })
+            .collect::<Vec<_>>();
+
+        Ok(params.join(";"))
+    }
+}
+
 impl crate::ser::Serialize for ContentLine {
     fn ical(&self) -> crate::Result<String> {
-        self.to_string().ical()
+        Ok(self.value.clone())
     }
 
     fn attr(&self) -> Option<String> {
@@ -1,17 +1,35 @@
 pub type Result<T = ()> = std::result::Result<T, Error>;
 
+/**
+ * Not serializable with the `serde` feature, some variants wrap the chrono
+ * and std errors. Use its [`std::fmt::Display`] text instead.
+ */
 #[derive(Debug, PartialEq, thiserror::Error)]
 pub enum Error {
     #[error("Invalid alarm action: {0}")]
     Alarm(String),
+    #[error("Invalid base64 value: {0}")]
+    Base64(String),
     #[error("{0}")]
     Date(#[from] chrono::ParseError),
+    #[error("Free/busy period not in UTC: {0}")]
+    FreeBusy(String),
     #[error("Invalid freq {0}")]
     Freq(String),
+    #[error("{0}")]
+    Io(String),
+    #[error("Invalid field {0}")]
+    Invalid(String),
+    #[error("Invalid jCal: {0}")]
+    JCal(String),
     #[error("Unknow key {0}")]
     Key(String),
     #[error("Invalid date in local timezone: {0:?}")]
     Local(crate::properties::DateTime),
+    #[error("Missing field {0}")]
+    Missing(String),
+    #[error("{0}")]
+    Parse(ParseError),
     #[error("{0}")]
     ParseDuration(String),
     #[error("{0}")]
@@ -20,18 +38,104 @@ pub enum Error {
     Parser(String),
     #[error("Invalid priority: {0}")]
     Priority(u8),
+    #[error("Invalid range: {0}")]
+    Range(String),
     #[error("{0}")]
     Serialize(String),
     #[error("Unknow status {0}")]
     Status(String),
+    #[error("Unknow time zone {0}")]
+    Timezone(String),
     #[error("Unknow time transparency {0}")]
     TimeTransparency(String),
     #[error("Invalid weekday {0}")]
     Weekday(String),
+    #[error("Invalid xCal: {0}")]
+    XCal(String),
+    #[error("{0}")]
+    Zoneinfo(String),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value.to_string())
+    }
+}
+
+impl From<nom::Err<nom_language::error::VerboseError<&str>>> for Error {
+    fn from(value: nom::Err<nom_language::error::VerboseError<&str>>) -> Self {
+        let (nom::Err::Error(err) | nom::Err::Failure(err)) = value else {
+            return Self::Parser("Incomplete input".to_string());
+        };
+
+        let expected = err.errors.iter().find_map(|(_, kind)| match kind {
+            nom_language::error::VerboseErrorKind::Context(context) => Some(*context),
+            _ => None,
+        });
+        let found = err
+            .errors
+            .first()
+            .and_then(|(input, _)| input.lines().next())
+            .unwrap_or_default();
+
+        match expected {
+            Some(expected) => Self::Parser(format!("Expected {expected}, found {found:?}")),
+            None => Self::Parser(format!("Unexpected {found:?}")),
+        }
+    }
+}
+
+/**
+ * Position and reason of a parsing error.
+ */
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParseError {
+    /**
+     * Number of the line, starting at 1.
+     */
+    pub line: usize,
+    /**
+     * Column in the unfolded line, starting at 1.
+     */
+    pub column: usize,
+    /**
+     * Path of the invalid element, like `VCALENDAR > VEVENT[3] > DTSTART`.
+     * Sub-components are indexed from 0 among the ones with the same name.
+     */
+    pub path: String,
+    pub property: Option<String>,
+    pub expected: String,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+
+        if !self.path.is_empty() {
+            write!(f, "{}: ", self.path)?;
+        }
+
+        f.write_str(&self.expected)
+    }
+}
+
+/**
+ * Lines skipped by [`crate::VCalendar::parse_lenient`].
+ */
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Diagnostic {
+    pub error: ParseError,
+    /**
+     * The raw lines dropped, a whole component is kept with its `BEGIN` and
+     * `END` lines.
+     */
+    pub content_lines: Vec<crate::ContentLine>,
 }
 
-impl<I: std::fmt::Debug> From<nom::Err<nom_language::error::VerboseError<I>>> for Error {
-    fn from(value: nom::Err<nom_language::error::VerboseError<I>>) -> Self {
-        Self::Parser(format!("{value:#?}"))
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.error.fmt(f)
     }
 }
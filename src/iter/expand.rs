@@ -0,0 +1,410 @@
+use chrono::{Datelike as _, Timelike as _};
+
+/**
+ * Last year generated, date-time values only have four digits for the year.
+ */
+const MAX_YEAR: i32 = 9999;
+
+/**
+ * Expands a recurrence rule into the date-times it describes.
+ *
+ * Each period of the rule frequency is expanded or limited by the `BYxxx`
+ * rule parts according to the table of
+ * [3.3.10. Recurrence Rule](https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.10),
+ * then `BYSETPOS` selects occurrences within the set of each period.
+ *
+ * Date-times are yielded in chronological order, starting from the first
+ * period containing `dtstart`. `COUNT` and `UNTIL` are not applied.
+ */
+pub(crate) struct Expand {
+    freq: crate::Freq,
+    interval: i64,
+    dtstart: chrono::NaiveDateTime,
+    wkst: chrono::Weekday,
+    months: Vec<u32>,
+    weeknos: Vec<i64>,
+    yeardays: Vec<i64>,
+    monthdays: Vec<i64>,
+    weekdays: Vec<(Option<i64>, chrono::Weekday)>,
+    hours: Vec<u32>,
+    minutes: Vec<u32>,
+    seconds: Vec<u32>,
+    setpos: Vec<i64>,
+    period: i64,
+    pending: std::collections::VecDeque<chrono::NaiveDateTime>,
+}
+
+impl Expand {
+    pub fn new(rule: &crate::Recur, dtstart: chrono::NaiveDateTime, date_only: bool) -> Self {
+        use crate::Freq;
+
+        let freq = rule.freq.clone();
+
+        let mut months = values(&rule.by_month, 1, 12);
+        let weeknos = signed_values(&rule.by_weekno, 53);
+        let yeardays = signed_values(&rule.by_yearday, 366);
+        let mut monthdays = signed_values(&rule.by_monthday, 31);
+        let mut weekdays = rule
+            .by_day
+            .iter()
+            .map(|x| (x.ord.map(i64::from), chrono::Weekday::from(&x.weekday)))
+            .collect::<Vec<_>>();
+
+        if weeknos.is_empty() && yeardays.is_empty() && monthdays.is_empty() && weekdays.is_empty()
+        {
+            match freq {
+                Freq::Yearly => {
+                    if months.is_empty() {
+                        months = vec![dtstart.month()];
+                    }
+                    monthdays = vec![dtstart.day().into()];
+                }
+                Freq::Monthly => monthdays = vec![dtstart.day().into()],
+                Freq::Weekly => weekdays = vec![(None, dtstart.weekday())],
+                _ => (),
+            }
+        }
+
+        let time = |by: &[i8], max, default, unit| {
+            if date_only {
+                vec![0]
+            } else if by.is_empty() && rule.freq > unit {
+                vec![default]
+            } else {
+                values(by, 0, max)
+            }
+        };
+
+        Self {
+            interval: rule.interval.max(1).into(),
+            wkst: rule
+                .wkst
+                .as_ref()
+                .map_or(chrono::Weekday::Mon, chrono::Weekday::from),
+            months,
+            weeknos,
+            yeardays,
+            monthdays,
+            weekdays,
+            hours: time(&rule.by_hour, 23, dtstart.hour(), Freq::Hourly),
+            minutes: time(&rule.by_minute, 59, dtstart.minute(), Freq::Minutely),
+            seconds: time(&rule.by_second, 60, dtstart.second(), Freq::Secondly),
+            setpos: signed_values(&rule.by_setpos, 366),
+            freq,
+            dtstart,
+            period: 0,
+            pending: std::collections::VecDeque::new(),
+        }
+    }
+
+    /**
+     * Fills `pending` with the date-times of the next period, returns
+     * `false` once the last representable year is reached.
+     */
+    fn fill(&mut self) -> bool {
+        use crate::Freq;
+
+        let period = self.period;
+        self.period += 1;
+
+        let days = match self.freq {
+            Freq::Yearly => {
+                let year = self.dtstart.year() as i64 + period * self.interval;
+                let Some(start) = date(year, 1, 1) else {
+                    return false;
+                };
+                let end = date(year + 1, 1, 1).unwrap_or(chrono::NaiveDate::MAX);
+
+                days(start, end)
+            }
+            Freq::Monthly => {
+                let month = self.dtstart.year() as i64 * 12
+                    + self.dtstart.month0() as i64
+                    + period * self.interval;
+                let Some(start) = date(month.div_euclid(12), month.rem_euclid(12) as u32 + 1, 1)
+                else {
+                    return false;
+                };
+                let end = start + chrono::Months::new(1);
+
+                days(start, end)
+            }
+            Freq::Weekly => {
+                let start = week_start(self.dtstart.date(), self.wkst)
+                    + chrono::TimeDelta::weeks(period * self.interval);
+
+                days(start, start + chrono::TimeDelta::weeks(1))
+            }
+            Freq::Daily => {
+                let start = self.dtstart.date() + chrono::TimeDelta::days(period * self.interval);
+
+                vec![start]
+            }
+            Freq::Hourly | Freq::Minutely | Freq::Secondly => return self.fill_time(period),
+        };
+
+        if days.first().is_some_and(|x| x.year() > MAX_YEAR) {
+            return false;
+        }
+
+        let mut set = Vec::new();
+
+        for day in days {
+            if !self.matches(day) {
+                continue;
+            }
+
+            for hour in &self.hours {
+                for minute in &self.minutes {
+                    for second in &self.seconds {
+                        if let Some(time) = chrono::NaiveTime::from_hms_opt(*hour, *minute, *second)
+                        {
+                            set.push(day.and_time(time));
+                        }
+                    }
+                }
+            }
+        }
+
+        self.push(set);
+
+        true
+    }
+
+    /**
+     * Same as `fill` for the frequencies shorter than a day.
+     *
+     * Periods whose day doesn’t match the rule are skipped until the next
+     * day, so limited rules don’t walk through every second of the year.
+     */
+    fn fill_time(&mut self, period: i64) -> bool {
+        use crate::Freq;
+
+        let unit = match self.freq {
+            Freq::Hourly => 3_600,
+            Freq::Minutely => 60,
+            _ => 1,
+        };
+        let step = unit * self.interval;
+        let first = self.dtstart.with_nanosecond(0).unwrap()
+            - chrono::TimeDelta::seconds(
+                i64::from(self.dtstart.num_seconds_from_midnight()) % unit,
+            );
+        let start = first + chrono::TimeDelta::seconds(period * step);
+
+        if start.year() > MAX_YEAR {
+            return false;
+        }
+
+        if !self.matches(start.date()) {
+            let next_day = start.date().succ_opt().unwrap_or(chrono::NaiveDate::MAX);
+            let elapsed = (next_day.and_time(chrono::NaiveTime::MIN) - first).num_seconds();
+            self.period = (elapsed + step - 1) / step;
+
+            return true;
+        }
+
+        let limit = |by: &[u32], value| by.is_empty() || by.contains(&value);
+
+        if !limit(&self.hours, start.hour())
+            || (self.freq <= Freq::Minutely && !limit(&self.minutes, start.minute()))
+            || (self.freq == Freq::Secondly && !limit(&self.seconds, start.second()))
+        {
+            return true;
+        }
+
+        let minutes = if self.freq == Freq::Hourly {
+            self.minutes.clone()
+        } else {
+            vec![start.minute()]
+        };
+        let seconds = if self.freq == Freq::Secondly {
+            vec![start.second()]
+        } else {
+            self.seconds.clone()
+        };
+
+        let mut set = Vec::new();
+
+        for minute in &minutes {
+            for second in &seconds {
+                if let Some(time) = chrono::NaiveTime::from_hms_opt(start.hour(), *minute, *second)
+                {
+                    set.push(start.date().and_time(time));
+                }
+            }
+        }
+
+        self.push(set);
+
+        true
+    }
+
+    /**
+     * Queues the date-times of a period, keeping only the positions listed
+     * by `BYSETPOS` if any.
+     */
+    fn push(&mut self, set: Vec<chrono::NaiveDateTime>) {
+        if self.setpos.is_empty() {
+            self.pending.extend(set);
+            return;
+        }
+
+        let len = set.len() as i64;
+        let mut selected = self
+            .setpos
+            .iter()
+            .filter_map(|pos| {
+                let index = if *pos > 0 { pos - 1 } else { len + pos };
+
+                usize::try_from(index).ok().and_then(|x| set.get(x))
+            })
+            .copied()
+            .collect::<Vec<_>>();
+
+        selected.sort_unstable();
+        selected.dedup();
+
+        self.pending.extend(selected);
+    }
+
+    fn matches(&self, day: chrono::NaiveDate) -> bool {
+        (self.months.is_empty() || self.months.contains(&day.month()))
+            && (self.weeknos.is_empty() || self.matches_weekno(day))
+            && (self.yeardays.is_empty() || self.matches_yearday(day))
+            && (self.monthdays.is_empty() || self.matches_monthday(day))
+            && (self.weekdays.is_empty() || self.matches_weekday(day))
+    }
+
+    fn matches_weekno(&self, day: chrono::NaiveDate) -> bool {
+        let start = week_start(day, self.wkst);
+        let year = (start + chrono::TimeDelta::days(3)).year();
+        let first = week_start(date(year.into(), 1, 4).unwrap(), self.wkst);
+        let next = week_start(
+            date(i64::from(year) + 1, 1, 4).unwrap_or(chrono::NaiveDate::MAX),
+            self.wkst,
+        );
+        let weekno = (start - first).num_weeks() + 1;
+        let weeks = (next - first).num_weeks();
+
+        self.weeknos.contains(&weekno) || self.weeknos.contains(&(weekno - weeks - 1))
+    }
+
+    fn matches_yearday(&self, day: chrono::NaiveDate) -> bool {
+        let yearday = i64::from(day.ordinal());
+        let len = if day.leap_year() { 366 } else { 365 };
+
+        self.yeardays.contains(&yearday) || self.yeardays.contains(&(yearday - len - 1))
+    }
+
+    fn matches_monthday(&self, day: chrono::NaiveDate) -> bool {
+        let monthday = i64::from(day.day());
+        let len = days_in_month(day);
+
+        self.monthdays.contains(&monthday) || self.monthdays.contains(&(monthday - len - 1))
+    }
+
+    /**
+     * The ordinal of a `BYDAY` is relative to the month for a monthly rule
+     * or a yearly rule limited by `BYMONTH`, to the year for other yearly
+     * rules and is meaningless otherwise.
+     */
+    fn matches_weekday(&self, day: chrono::NaiveDate) -> bool {
+        use crate::Freq;
+
+        let (start, end) = match self.freq {
+            Freq::Monthly => month_bounds(day),
+            Freq::Yearly if self.weeknos.is_empty() => {
+                if self.months.is_empty() {
+                    (
+                        date(day.year().into(), 1, 1).unwrap(),
+                        date(day.year().into(), 12, 31).unwrap(),
+                    )
+                } else {
+                    month_bounds(day)
+                }
+            }
+            _ => (day, day),
+        };
+
+        self.weekdays.iter().any(|(ord, weekday)| {
+            if *weekday != day.weekday() {
+                return false;
+            }
+
+            match ord {
+                Some(ord) if start != end => {
+                    if *ord > 0 {
+                        (day - start).num_days() / 7 + 1 == *ord
+                    } else {
+                        (end - day).num_days() / 7 + 1 == -ord
+                    }
+                }
+                _ => true,
+            }
+        })
+    }
+}
+
+impl Iterator for Expand {
+    type Item = chrono::NaiveDateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pending.is_empty() {
+            if !self.fill() {
+                return None;
+            }
+        }
+
+        self.pending.pop_front()
+    }
+}
+
+fn values(by: &[i8], min: i8, max: i8) -> Vec<u32> {
+    let mut values = by
+        .iter()
+        .filter(|x| (min..=max).contains(*x))
+        .map(|x| *x as u32)
+        .collect::<Vec<_>>();
+
+    values.sort_unstable();
+    values.dedup();
+
+    values
+}
+
+fn signed_values<T: Copy + Into<i64>>(by: &[T], max: i64) -> Vec<i64> {
+    by.iter()
+        .map(|x| (*x).into())
+        .filter(|x: &i64| *x != 0 && x.abs() <= max)
+        .collect()
+}
+
+fn date(year: i64, month: u32, day: u32) -> Option<chrono::NaiveDate> {
+    chrono::NaiveDate::from_ymd_opt(year.try_into().ok()?, month, day)
+}
+
+fn days(start: chrono::NaiveDate, end: chrono::NaiveDate) -> Vec<chrono::NaiveDate> {
+    start.iter_days().take_while(|x| *x < end).collect()
+}
+
+fn days_in_month(day: chrono::NaiveDate) -> i64 {
+    let (start, end) = month_bounds(day);
+
+    (end - start).num_days() + 1
+}
+
+fn month_bounds(day: chrono::NaiveDate) -> (chrono::NaiveDate, chrono::NaiveDate) {
+    let start = day.with_day(1).unwrap();
+    let end = (start + chrono::Months::new(1))
+        .pred_opt()
+        .unwrap_or(chrono::NaiveDate::MAX);
+
+    (start, end)
+}
+
+fn week_start(day: chrono::NaiveDate, wkst: chrono::Weekday) -> chrono::NaiveDate {
+    let offset = (day.weekday().num_days_from_monday() + 7 - wkst.num_days_from_monday()) % 7;
+
+    day - chrono::TimeDelta::days(offset.into())
+}
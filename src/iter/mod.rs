@@ -0,0 +1,1496 @@
+mod expand;
+
+pub trait Recurring: Clone {
+    fn dtstart(&self) -> Option<&crate::Date> {
+        None
+    }
+
+    fn exdate(&self) -> &[crate::Date];
+
+    fn set_dtstart(&mut self, _dtstart: crate::Date) {}
+
+    fn dtend(&self) -> Option<&crate::Date> {
+        None
+    }
+
+    fn set_dtend(&mut self, _dtend: crate::Date) {}
+
+    fn due(&self) -> Option<&crate::Date> {
+        None
+    }
+
+    fn set_due(&mut self, _dtend: crate::Date) {}
+
+    fn set_duration(&mut self, _duration: crate::Duration) {}
+
+    fn rdate(&self) -> &[crate::RDate] {
+        &[]
+    }
+
+    fn rrule(&self) -> Option<&crate::Recur>;
+    fn set_rrule(&mut self, rrule: crate::Recur);
+
+    /**
+     * The UTC offset of an occurrence starting at the floating `local` time,
+     * to compare it with a UTC `UNTIL`.
+     */
+    fn offset(&self, _local: chrono::NaiveDateTime) -> Option<chrono::FixedOffset> {
+        None
+    }
+}
+
+impl Recurring for crate::VEvent {
+    fn dtstart(&self) -> Option<&crate::Date> {
+        Some(&self.dtstart)
+    }
+
+    fn exdate(&self) -> &[crate::Date] {
+        &self.exdate
+    }
+
+    fn set_dtstart(&mut self, dtstart: crate::Date) {
+        self.dtstart = dtstart;
+    }
+
+    fn dtend(&self) -> Option<&crate::Date> {
+        self.dtend.as_ref()
+    }
+
+    fn set_dtend(&mut self, dtend: crate::Date) {
+        self.dtend = Some(dtend);
+    }
+
+    fn set_duration(&mut self, duration: crate::Duration) {
+        self.duration = Some(duration);
+    }
+
+    fn rdate(&self) -> &[crate::RDate] {
+        &self.rdate
+    }
+
+    fn rrule(&self) -> Option<&crate::Recur> {
+        self.rrule.as_ref()
+    }
+
+    fn set_rrule(&mut self, rrule: crate::Recur) {
+        self.rrule = Some(rrule);
+    }
+}
+
+impl Recurring for crate::VJournal {
+    fn dtstart(&self) -> Option<&crate::Date> {
+        Some(&self.dtstart)
+    }
+
+    fn exdate(&self) -> &[crate::Date] {
+        &self.exdate
+    }
+
+    fn set_dtstart(&mut self, dtstart: crate::Date) {
+        self.dtstart = dtstart;
+    }
+
+    fn rdate(&self) -> &[crate::RDate] {
+        &self.rdate
+    }
+
+    fn rrule(&self) -> Option<&crate::Recur> {
+        self.rrule.as_ref()
+    }
+
+    fn set_rrule(&mut self, rrule: crate::Recur) {
+        self.rrule = Some(rrule);
+    }
+}
+
+impl Recurring for crate::VTodo {
+    fn dtstart(&self) -> Option<&crate::Date> {
+        self.dtstart.as_ref()
+    }
+
+    fn exdate(&self) -> &[crate::Date] {
+        &self.exdate
+    }
+
+    fn set_dtstart(&mut self, dtstart: crate::Date) {
+        self.dtstart = Some(dtstart);
+    }
+
+    fn due(&self) -> Option<&crate::Date> {
+        self.due.as_ref()
+    }
+
+    fn set_due(&mut self, due: crate::Date) {
+        self.due = Some(due);
+    }
+
+    fn set_duration(&mut self, duration: crate::Duration) {
+        self.duration = Some(duration);
+    }
+
+    fn rdate(&self) -> &[crate::RDate] {
+        &self.rdate
+    }
+
+    fn rrule(&self) -> Option<&crate::Recur> {
+        self.rrule.as_ref()
+    }
+
+    fn set_rrule(&mut self, rrule: crate::Recur) {
+        self.rrule = Some(rrule);
+    }
+}
+
+/**
+ * Iterates over the recurrence set of a component.
+ *
+ * See [3.8.5. Recurrence Component Properties](https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.5)
+ */
+pub struct Recur<T: Recurring> {
+    item: T,
+    expand: Option<expand::Expand>,
+    started: bool,
+    generated: usize,
+    rule: Option<crate::Date>,
+    rdates: std::collections::VecDeque<(crate::Date, Option<crate::Duration>)>,
+    last: Option<crate::Date>,
+    timezone: std::cell::OnceCell<Option<crate::VTimezone>>,
+}
+
+impl<T: Recurring> Recur<T> {
+    pub(crate) fn from(item: &T) -> Self {
+        let expand = match (item.dtstart(), item.rrule()) {
+            (Some(dtstart), Some(rrule)) => Some(expand::Expand::new(
+                rrule,
+                dtstart.naive(),
+                !dtstart.has_time() && rrule.freq >= crate::Freq::Daily,
+            )),
+            _ => None,
+        };
+
+        let mut rdates = item
+            .rdate()
+            .iter()
+            .flat_map(|rdate| match rdate {
+                crate::RDate::Date(dates) => {
+                    dates.iter().map(|x| (x.clone(), None)).collect::<Vec<_>>()
+                }
+                crate::RDate::Period(periods) => periods
+                    .iter()
+                    .map(|x| (x.start().into(), Some(x.duration())))
+                    .collect(),
+            })
+            .collect::<Vec<_>>();
+        rdates.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        Self {
+            item: item.clone(),
+            expand,
+            started: false,
+            generated: 0,
+            rule: None,
+            rdates: rdates.into(),
+            last: None,
+            timezone: std::cell::OnceCell::new(),
+        }
+    }
+
+    /**
+     * Resolves the `TZID` of the start with `vtimezone`, instead of the
+     * zoneinfo files.
+     */
+    pub(crate) fn with_timezone(self, vtimezone: Option<&crate::VTimezone>) -> Self {
+        if let Some(vtimezone) = vtimezone {
+            self.timezone.set(Some(vtimezone.clone())).ok();
+        }
+
+        self
+    }
+
+    pub fn between<D: Into<crate::Date>>(self, start: D, end: D) -> impl Iterator<Item = T> {
+        let start = start.into();
+        let end = end.into();
+
+        self.skip_while(move |x| x.dtstart().unwrap() < &start)
+            .take_while(move |x| x.dtstart().unwrap() < &end)
+    }
+
+    pub fn at<D: Into<crate::Date>>(self, date: D) -> impl Iterator<Item = T> {
+        let date = date.into();
+        let delta = chrono::TimeDelta::days(1);
+        self.between(date.clone(), date + delta)
+    }
+
+    pub fn after<D: Into<crate::Date>>(self, date: D) -> impl Iterator<Item = T> {
+        let date = date.into();
+
+        self.skip_while(move |x| x.dtstart().unwrap() < &date)
+    }
+
+    /**
+     * Next start generated by the recurrence rule, the `DTSTART` always
+     * being the first one.
+     */
+    fn next_start(&mut self) -> Option<crate::Date> {
+        let dtstart = self.item.dtstart()?.clone();
+
+        if !self.started {
+            self.started = true;
+            return Some(dtstart);
+        }
+
+        let rrule = self.item.rrule()?;
+        let naive = self.expand.as_mut()?.find(|x| *x > dtstart.naive())?;
+
+        let start = if dtstart.has_time() || rrule.freq >= crate::Freq::Daily {
+            dtstart.with_naive(naive)
+        } else {
+            crate::DateTime::Naive(naive).into()
+        };
+
+        if let Some(until) = &rrule.until {
+            let after = match until {
+                crate::Date::Date(until) => start.date_naive() > *until,
+                crate::Date::DateTime(crate::DateTime::Utc(until)) => {
+                    let utc = self.utc(&start).unwrap_or(naive);
+
+                    utc > until.naive_utc()
+                }
+                crate::Date::DateTime(until) => naive > until.naive(),
+            };
+
+            if after {
+                return None;
+            }
+        }
+
+        Some(start)
+    }
+
+    /**
+     * The UTC time of the `start` of an occurrence, `None` for a floating
+     * time without offset or an unknown time zone.
+     */
+    fn utc(&self, start: &crate::Date) -> Option<chrono::NaiveDateTime> {
+        let crate::Date::DateTime(date_time) = start else {
+            return None;
+        };
+
+        match date_time {
+            crate::DateTime::Naive(naive) => self.item.offset(*naive).map(|x| *naive - x),
+            crate::DateTime::Local(local) => Some(local.naive_utc()),
+            crate::DateTime::Utc(utc) => Some(utc.naive_utc()),
+            crate::DateTime::Zoned(naive, tzid) => self
+                .vtimezone(tzid, *naive)?
+                .to_utc(*naive)
+                .map(|x| x.naive_utc()),
+        }
+    }
+
+    /**
+     * The definition of the `tzid` time zone of the start, given with
+     * [`Self::with_timezone`] or read from the zoneinfo files.
+     */
+    fn vtimezone(&self, tzid: &str, naive: chrono::NaiveDateTime) -> Option<&crate::VTimezone> {
+        use chrono::Datelike as _;
+
+        self.timezone
+            .get_or_init(|| {
+                let first = self
+                    .item
+                    .dtstart()
+                    .map_or(naive.year(), |x| x.naive().year());
+                let last = match self.item.rrule().and_then(|x| x.until.as_ref()) {
+                    Some(until) => until.naive().year(),
+                    None => naive.year(),
+                };
+
+                crate::VTimezone::from_zoneinfo(tzid, first..=last).ok()
+            })
+            .as_ref()
+    }
+
+    /**
+     * Same as `next_start` with the `COUNT` limit applied.
+     */
+    fn next_rule(&mut self) -> Option<crate::Date> {
+        let start = self.next_start()?;
+
+        if let Some(count) = self.item.rrule().and_then(|x| x.count)
+            && self.generated >= count.into()
+        {
+            return None;
+        }
+        self.generated += 1;
+
+        Some(start)
+    }
+
+    fn occurrence(&self, start: crate::Date, duration: Option<crate::Duration>) -> T {
+        let mut item = self.item.clone();
+        let delta = start.naive()
+            - self
+                .item
+                .dtstart()
+                .map(crate::Date::naive)
+                .unwrap_or_default();
+
+        item.set_dtstart(start.clone());
+
+        if let Some(duration) = duration {
+            let end = match start.tzid().and_then(|x| self.vtimezone(x, start.naive())) {
+                Some(vtimezone) => start.add_in(duration, vtimezone),
+                None => start + duration,
+            };
+
+            if self.item.dtend().is_some() {
+                item.set_dtend(end);
+            } else if self.item.due().is_some() {
+                item.set_due(end);
+            } else {
+                item.set_duration(duration);
+            }
+
+            return item;
+        }
+
+        if let Some(dtend) = self.item.dtend() {
+            item.set_dtend(dtend.with_naive(dtend.naive() + delta));
+        }
+
+        if let Some(due) = self.item.due() {
+            item.set_due(due.with_naive(due.naive() + delta));
+        }
+
+        item
+    }
+}
+
+impl<T: Recurring> Iterator for Recur<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.rule.is_none() {
+                self.rule = self.next_rule();
+            }
+
+            let (start, duration) = match (self.rule.clone(), self.rdates.front()) {
+                (None, None) => return None,
+                (Some(rule), rdate) if rdate.is_none_or(|(rdate, _)| rule < *rdate) => {
+                    self.rule = None;
+                    (rule, None)
+                }
+                (rule, _) => {
+                    let (rdate, duration) = self.rdates.pop_front()?;
+
+                    if rule.as_ref() == Some(&rdate) {
+                        self.rule = None;
+                    }
+
+                    (rdate, duration)
+                }
+            };
+
+            if self.last.as_ref() == Some(&start) {
+                continue;
+            }
+            self.last = Some(start.clone());
+
+            if self.item.exdate().contains(&start) {
+                continue;
+            }
+
+            return Some(self.occurrence(start, duration));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    fn assert_rrule(dtstart: &str, rrule: &str, expected: &str) {
+        assert_recurrence(dtstart, rrule, &[], expected);
+    }
+
+    fn assert_recurrence(dtstart: &str, rrule: &str, exdate: &[&str], expected: &str) {
+        let event = crate::VEvent {
+            dtstart: dtstart.parse().unwrap(),
+            rrule: Some(rrule.parse().unwrap()),
+            exdate: exdate.iter().map(|x| x.parse().unwrap()).collect(),
+            ..Default::default()
+        };
+
+        let expected = expected.split_whitespace().collect::<Vec<_>>();
+        // a limited rule must stop after the last expected occurrence
+        let limited = rrule.contains("COUNT=") || rrule.contains("UNTIL=");
+        let len = if limited {
+            expected.len() + 1
+        } else {
+            expected.len()
+        };
+
+        let actual = event
+            .recurrent()
+            .take(len)
+            .map(|x| x.dtstart.to_string())
+            .collect::<Vec<_>>();
+
+        similar_asserts::assert_eq!(actual, expected, "{rrule}");
+    }
+
+    #[test]
+    fn at() {
+        let now: crate::Date = chrono::Local::now().date_naive().into();
+
+        let event = crate::vevent! {
+            dtstart: "20240101",
+            dtend: "20240101",
+            rrule: {
+                freq: Daily,
+                interval: 1,
+            }
+        }
+        .unwrap();
+
+        let next = event.recurrent().at(now.clone()).next().unwrap();
+
+        assert_eq!(next.dtstart, now);
+        assert_eq!(next.dtend, Some(now));
+    }
+
+    #[test]
+    fn count() {
+        let event = crate::vevent! {
+            rrule: {
+                freq: Weekly,
+                interval: 1,
+                count: 10,
+            }
+        }
+        .unwrap();
+
+        let events = event.recurrent();
+
+        assert_eq!(events.count(), 10);
+    }
+
+    #[test]
+    fn after() {
+        let now: crate::Date = chrono::Local::now().into();
+
+        let event = crate::vevent! {
+            rrule: {
+                freq: Monthly,
+                interval: 1,
+                until: now.clone(),
+            }
+        }
+        .unwrap();
+
+        let mut events = event.recurrent().after(now);
+
+        assert_eq!(events.next(), None);
+    }
+
+    #[test]
+    fn between() {
+        let now = chrono::Local::now();
+
+        let event = crate::vevent! {
+            rrule: {
+                freq: Yearly,
+                interval: 10,
+            }
+        }
+        .unwrap();
+
+        use chrono::Datelike as _;
+        let end = now.with_year(now.year() + 20).unwrap();
+        let events = event.recurrent().between(now, end);
+
+        assert_eq!(events.count(), 2);
+    }
+
+    #[test]
+    fn exdate() {
+        let event = crate::vevent! {
+            dtstart: "20240101",
+            rrule: {
+                freq: Yearly,
+                interval: 1,
+                count: 10,
+            },
+            exdate: ["20250101"],
+        }
+        .unwrap();
+
+        let mut events = event.recurrent();
+
+        assert_eq!(events.nth(1).unwrap().dtstart, "20260101".parse().unwrap());
+    }
+
+    #[test]
+    fn rdate() -> crate::Result {
+        let event = crate::VEvent {
+            dtstart: "20240101T090000".parse()?,
+            dtend: Some("20240101T100000".parse()?),
+            rrule: Some("FREQ=DAILY;COUNT=3".parse()?),
+            rdate: vec![
+                crate::RDate::Date(vec!["20240110T090000".parse()?, "20240102T090000".parse()?]),
+                crate::RDate::Period(vec!["20240105T140000/PT3H".parse()?]),
+            ],
+            exdate: vec!["20240110T090000".parse()?],
+            ..Default::default()
+        };
+
+        let events = event
+            .recurrent()
+            .map(|x| (x.dtstart.to_string(), x.dtend.unwrap().to_string()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            events,
+            [
+                ("20240101T090000".to_string(), "20240101T100000".to_string()),
+                ("20240102T090000".to_string(), "20240102T100000".to_string()),
+                ("20240103T090000".to_string(), "20240103T100000".to_string()),
+                ("20240105T140000".to_string(), "20240105T170000".to_string()),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn rdate_without_rrule() -> crate::Result {
+        let vtodo = crate::VTodo {
+            dtstart: Some("20240101".parse()?),
+            rdate: vec![crate::RDate::Date(vec!["20240201".parse()?])],
+            ..Default::default()
+        };
+
+        let todos = vtodo
+            .recurrent()
+            .map(|x| x.dtstart.unwrap().to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(todos, ["20240101", "20240201"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn rdate_period_duration() -> crate::Result {
+        let event = crate::VEvent {
+            dtstart: "20240101T090000".parse()?,
+            rdate: vec![crate::RDate::Period(vec![
+                "20240102T090000/20240102T113000".parse()?,
+            ])],
+            ..Default::default()
+        };
+
+        let event = event.recurrent().nth(1).unwrap();
+
+        assert_eq!(event.dtstart, "20240102T090000".parse()?);
+        assert_eq!(event.duration, Some(crate::Duration::minutes(150)));
+        assert_eq!(event.end(), "20240102T113000".parse()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn rdate_period_zoned() -> crate::Result {
+        let zoned = |s: &str| -> crate::Result<crate::DateTime> {
+            let naive = s.parse::<crate::DateTime>()?.naive();
+
+            Ok(crate::DateTime::Zoned(naive, "Europe/Paris".to_string()))
+        };
+
+        let event = crate::VEvent {
+            dtstart: zoned("20240323T120000")?.into(),
+            dtend: Some(zoned("20240323T130000")?.into()),
+            rdate: vec![crate::RDate::Period(vec![crate::Period::StartDur(
+                crate::period::StartDur {
+                    start: zoned("20240330T120000")?,
+                    duration: "PT24H".parse()?,
+                },
+            )])],
+            ..Default::default()
+        };
+
+        let event = event.recurrent().nth(1).unwrap();
+
+        // the 24 hours elapse across the DST change
+        assert_eq!(event.dtend, Some(zoned("20240331T130000")?.into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn duration_across_dst() -> crate::Result {
+        use chrono::TimeZone as _;
+
+        let event = crate::VEvent {
+            dtstart: crate::DateTime::Local(
+                chrono::Local
+                    .with_ymd_and_hms(2024, 3, 30, 12, 0, 0)
+                    .unwrap(),
+            )
+            .into(),
+            duration: Some(crate::Duration::days(1)),
+            rrule: Some("FREQ=DAILY;COUNT=2".parse()?),
+            ..Default::default()
+        };
+
+        let events = event
+            .recurrent()
+            .map(|x| x.end().format("%F %T").to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(events, ["2024-03-31 12:00:00", "2024-04-01 12:00:00"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn until_utc() -> crate::Result {
+        let event = crate::VEvent::try_from(
+            "BEGIN:VEVENT\r
+UID:until@example.com\r
+DTSTAMP:20240101T000000Z\r
+DTSTART;TZID=Europe/Paris:20240108T090000\r
+RRULE:FREQ=DAILY;UNTIL=20240110T083000Z\r
+END:VEVENT\r
+",
+        )?;
+
+        let events = event
+            .recurrent()
+            .map(|x| x.dtstart.to_string())
+            .collect::<Vec<_>>();
+
+        // 2024-01-10 09:00 in Paris is 08:00 UTC, before UNTIL
+        assert_eq!(
+            events,
+            ["20240108T090000", "20240109T090000", "20240110T090000"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn vjournal() {
+        let vjournal = crate::vjournal! {
+            rrule: {
+                freq: Daily,
+                count: 2,
+            }
+        }
+        .unwrap();
+
+        let iter = vjournal.recurrent();
+
+        assert_eq!(iter.count(), 2);
+    }
+
+    #[test]
+    fn vtodo() {
+        let vtodo = crate::vtodo! {
+            dtstart: "20240101",
+            rrule: {
+                freq: Daily,
+                count: 2,
+            }
+        }
+        .unwrap();
+
+        let iter = vtodo.recurrent();
+
+        assert_eq!(iter.count(), 2);
+    }
+
+    #[test]
+    fn last_weekday_of_month() {
+        assert_rrule(
+            "20240131T170000",
+            "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1",
+            "
+            20240131T170000 20240229T170000 20240329T170000 20240430T170000
+            20240531T170000 20240628T170000
+            ",
+        );
+    }
+
+    #[test]
+    fn by_setpos_first_and_last() {
+        assert_rrule(
+            "20240101T090000",
+            "FREQ=YEARLY;COUNT=4;BYMONTH=1,7;BYDAY=MO;BYSETPOS=1,-1",
+            "
+            20240101T090000 20240729T090000 20250106T090000 20250728T090000
+            ",
+        );
+    }
+
+    #[test]
+    fn by_setpos_times() {
+        assert_rrule(
+            "20240102T080000",
+            "FREQ=DAILY;COUNT=4;BYHOUR=8,12,18;BYSETPOS=-1,1",
+            "
+            20240102T080000 20240102T180000 20240103T080000 20240103T180000
+            ",
+        );
+    }
+
+    /**
+     * Daily for 10 occurrences
+     */
+    #[test]
+    fn rfc_daily_count() {
+        assert_rrule(
+            "19970902T090000",
+            "FREQ=DAILY;COUNT=10",
+            "
+            19970902T090000 19970903T090000 19970904T090000 19970905T090000
+            19970906T090000 19970907T090000 19970908T090000 19970909T090000
+            19970910T090000 19970911T090000
+            ",
+        );
+    }
+
+    /**
+     * Daily until December 24, 1997
+     */
+    #[test]
+    fn rfc_daily_until() {
+        assert_rrule(
+            "19970902T090000",
+            "FREQ=DAILY;UNTIL=19971224T000000",
+            "
+            19970902T090000 19970903T090000 19970904T090000 19970905T090000
+            19970906T090000 19970907T090000 19970908T090000 19970909T090000
+            19970910T090000 19970911T090000 19970912T090000 19970913T090000
+            19970914T090000 19970915T090000 19970916T090000 19970917T090000
+            19970918T090000 19970919T090000 19970920T090000 19970921T090000
+            19970922T090000 19970923T090000 19970924T090000 19970925T090000
+            19970926T090000 19970927T090000 19970928T090000 19970929T090000
+            19970930T090000 19971001T090000 19971002T090000 19971003T090000
+            19971004T090000 19971005T090000 19971006T090000 19971007T090000
+            19971008T090000 19971009T090000 19971010T090000 19971011T090000
+            19971012T090000 19971013T090000 19971014T090000 19971015T090000
+            19971016T090000 19971017T090000 19971018T090000 19971019T090000
+            19971020T090000 19971021T090000 19971022T090000 19971023T090000
+            19971024T090000 19971025T090000 19971026T090000 19971027T090000
+            19971028T090000 19971029T090000 19971030T090000 19971031T090000
+            19971101T090000 19971102T090000 19971103T090000 19971104T090000
+            19971105T090000 19971106T090000 19971107T090000 19971108T090000
+            19971109T090000 19971110T090000 19971111T090000 19971112T090000
+            19971113T090000 19971114T090000 19971115T090000 19971116T090000
+            19971117T090000 19971118T090000 19971119T090000 19971120T090000
+            19971121T090000 19971122T090000 19971123T090000 19971124T090000
+            19971125T090000 19971126T090000 19971127T090000 19971128T090000
+            19971129T090000 19971130T090000 19971201T090000 19971202T090000
+            19971203T090000 19971204T090000 19971205T090000 19971206T090000
+            19971207T090000 19971208T090000 19971209T090000 19971210T090000
+            19971211T090000 19971212T090000 19971213T090000 19971214T090000
+            19971215T090000 19971216T090000 19971217T090000 19971218T090000
+            19971219T090000 19971220T090000 19971221T090000 19971222T090000
+            19971223T090000
+            ",
+        );
+    }
+
+    /**
+     * Every other day - forever
+     */
+    #[test]
+    fn rfc_daily_interval() {
+        assert_rrule(
+            "19970902T090000",
+            "FREQ=DAILY;INTERVAL=2",
+            "
+            19970902T090000 19970904T090000 19970906T090000 19970908T090000
+            19970910T090000 19970912T090000 19970914T090000 19970916T090000
+            19970918T090000 19970920T090000 19970922T090000 19970924T090000
+            19970926T090000 19970928T090000 19970930T090000 19971002T090000
+            19971004T090000 19971006T090000 19971008T090000 19971010T090000
+            ",
+        );
+    }
+
+    /**
+     * Every 10 days, 5 occurrences
+     */
+    #[test]
+    fn rfc_daily_interval_count() {
+        assert_rrule(
+            "19970902T090000",
+            "FREQ=DAILY;INTERVAL=10;COUNT=5",
+            "
+            19970902T090000 19970912T090000 19970922T090000 19971002T090000
+            19971012T090000
+            ",
+        );
+    }
+
+    /**
+     * Every day in January, for 3 years
+     */
+    #[test]
+    fn rfc_yearly_every_day_in_january() {
+        assert_rrule(
+            "19980101T090000",
+            "FREQ=YEARLY;UNTIL=20000131T140000;BYMONTH=1;BYDAY=SU,MO,TU,WE,TH,FR,SA",
+            "
+            19980101T090000 19980102T090000 19980103T090000 19980104T090000
+            19980105T090000 19980106T090000 19980107T090000 19980108T090000
+            19980109T090000 19980110T090000 19980111T090000 19980112T090000
+            19980113T090000 19980114T090000 19980115T090000 19980116T090000
+            19980117T090000 19980118T090000 19980119T090000 19980120T090000
+            19980121T090000 19980122T090000 19980123T090000 19980124T090000
+            19980125T090000 19980126T090000 19980127T090000 19980128T090000
+            19980129T090000 19980130T090000 19980131T090000 19990101T090000
+            19990102T090000 19990103T090000 19990104T090000 19990105T090000
+            19990106T090000 19990107T090000 19990108T090000 19990109T090000
+            19990110T090000 19990111T090000 19990112T090000 19990113T090000
+            19990114T090000 19990115T090000 19990116T090000 19990117T090000
+            19990118T090000 19990119T090000 19990120T090000 19990121T090000
+            19990122T090000 19990123T090000 19990124T090000 19990125T090000
+            19990126T090000 19990127T090000 19990128T090000 19990129T090000
+            19990130T090000 19990131T090000 20000101T090000 20000102T090000
+            20000103T090000 20000104T090000 20000105T090000 20000106T090000
+            20000107T090000 20000108T090000 20000109T090000 20000110T090000
+            20000111T090000 20000112T090000 20000113T090000 20000114T090000
+            20000115T090000 20000116T090000 20000117T090000 20000118T090000
+            20000119T090000 20000120T090000 20000121T090000 20000122T090000
+            20000123T090000 20000124T090000 20000125T090000 20000126T090000
+            20000127T090000 20000128T090000 20000129T090000 20000130T090000
+            20000131T090000
+            ",
+        );
+    }
+
+    /**
+     * Every day in January, for 3 years
+     */
+    #[test]
+    fn rfc_daily_every_day_in_january() {
+        assert_rrule(
+            "19980101T090000",
+            "FREQ=DAILY;UNTIL=20000131T140000;BYMONTH=1",
+            "
+            19980101T090000 19980102T090000 19980103T090000 19980104T090000
+            19980105T090000 19980106T090000 19980107T090000 19980108T090000
+            19980109T090000 19980110T090000 19980111T090000 19980112T090000
+            19980113T090000 19980114T090000 19980115T090000 19980116T090000
+            19980117T090000 19980118T090000 19980119T090000 19980120T090000
+            19980121T090000 19980122T090000 19980123T090000 19980124T090000
+            19980125T090000 19980126T090000 19980127T090000 19980128T090000
+            19980129T090000 19980130T090000 19980131T090000 19990101T090000
+            19990102T090000 19990103T090000 19990104T090000 19990105T090000
+            19990106T090000 19990107T090000 19990108T090000 19990109T090000
+            19990110T090000 19990111T090000 19990112T090000 19990113T090000
+            19990114T090000 19990115T090000 19990116T090000 19990117T090000
+            19990118T090000 19990119T090000 19990120T090000 19990121T090000
+            19990122T090000 19990123T090000 19990124T090000 19990125T090000
+            19990126T090000 19990127T090000 19990128T090000 19990129T090000
+            19990130T090000 19990131T090000 20000101T090000 20000102T090000
+            20000103T090000 20000104T090000 20000105T090000 20000106T090000
+            20000107T090000 20000108T090000 20000109T090000 20000110T090000
+            20000111T090000 20000112T090000 20000113T090000 20000114T090000
+            20000115T090000 20000116T090000 20000117T090000 20000118T090000
+            20000119T090000 20000120T090000 20000121T090000 20000122T090000
+            20000123T090000 20000124T090000 20000125T090000 20000126T090000
+            20000127T090000 20000128T090000 20000129T090000 20000130T090000
+            20000131T090000
+            ",
+        );
+    }
+
+    /**
+     * Weekly for 10 occurrences
+     */
+    #[test]
+    fn rfc_weekly_count() {
+        assert_rrule(
+            "19970902T090000",
+            "FREQ=WEEKLY;COUNT=10",
+            "
+            19970902T090000 19970909T090000 19970916T090000 19970923T090000
+            19970930T090000 19971007T090000 19971014T090000 19971021T090000
+            19971028T090000 19971104T090000
+            ",
+        );
+    }
+
+    /**
+     * Weekly until December 24, 1997
+     */
+    #[test]
+    fn rfc_weekly_until() {
+        assert_rrule(
+            "19970902T090000",
+            "FREQ=WEEKLY;UNTIL=19971224T000000",
+            "
+            19970902T090000 19970909T090000 19970916T090000 19970923T090000
+            19970930T090000 19971007T090000 19971014T090000 19971021T090000
+            19971028T090000 19971104T090000 19971111T090000 19971118T090000
+            19971125T090000 19971202T090000 19971209T090000 19971216T090000
+            19971223T090000
+            ",
+        );
+    }
+
+    /**
+     * Every other week - forever
+     */
+    #[test]
+    fn rfc_weekly_interval() {
+        assert_rrule(
+            "19970902T090000",
+            "FREQ=WEEKLY;INTERVAL=2;WKST=SU",
+            "
+            19970902T090000 19970916T090000 19970930T090000 19971014T090000
+            19971028T090000 19971111T090000 19971125T090000 19971209T090000
+            19971223T090000 19980106T090000 19980120T090000 19980203T090000
+            19980217T090000
+            ",
+        );
+    }
+
+    /**
+     * Weekly on Tuesday and Thursday for five weeks
+     */
+    #[test]
+    fn rfc_weekly_by_day_until() {
+        assert_rrule(
+            "19970902T090000",
+            "FREQ=WEEKLY;UNTIL=19971007T000000;WKST=SU;BYDAY=TU,TH",
+            "
+            19970902T090000 19970904T090000 19970909T090000 19970911T090000
+            19970916T090000 19970918T090000 19970923T090000 19970925T090000
+            19970930T090000 19971002T090000
+            ",
+        );
+    }
+
+    /**
+     * Weekly on Tuesday and Thursday for five weeks
+     */
+    #[test]
+    fn rfc_weekly_by_day_count() {
+        assert_rrule(
+            "19970902T090000",
+            "FREQ=WEEKLY;COUNT=10;WKST=SU;BYDAY=TU,TH",
+            "
+            19970902T090000 19970904T090000 19970909T090000 19970911T090000
+            19970916T090000 19970918T090000 19970923T090000 19970925T090000
+            19970930T090000 19971002T090000
+            ",
+        );
+    }
+
+    /**
+     * Every other week on Monday, Wednesday, and Friday until December 24, 1997
+     */
+    #[test]
+    fn rfc_weekly_interval_by_day_until() {
+        assert_rrule(
+            "19970901T090000",
+            "FREQ=WEEKLY;INTERVAL=2;UNTIL=19971224T000000;WKST=SU;BYDAY=MO,WE,FR",
+            "
+            19970901T090000 19970903T090000 19970905T090000 19970915T090000
+            19970917T090000 19970919T090000 19970929T090000 19971001T090000
+            19971003T090000 19971013T090000 19971015T090000 19971017T090000
+            19971027T090000 19971029T090000 19971031T090000 19971110T090000
+            19971112T090000 19971114T090000 19971124T090000 19971126T090000
+            19971128T090000 19971208T090000 19971210T090000 19971212T090000
+            19971222T090000
+            ",
+        );
+    }
+
+    /**
+     * Every other week on Tuesday and Thursday, for 8 occurrences
+     */
+    #[test]
+    fn rfc_weekly_interval_by_day_count() {
+        assert_rrule(
+            "19970902T090000",
+            "FREQ=WEEKLY;INTERVAL=2;COUNT=8;WKST=SU;BYDAY=TU,TH",
+            "
+            19970902T090000 19970904T090000 19970916T090000 19970918T090000
+            19970930T090000 19971002T090000 19971014T090000 19971016T090000
+            ",
+        );
+    }
+
+    /**
+     * Monthly on the first Friday for 10 occurrences
+     */
+    #[test]
+    fn rfc_monthly_by_day_count() {
+        assert_rrule(
+            "19970905T090000",
+            "FREQ=MONTHLY;COUNT=10;BYDAY=1FR",
+            "
+            19970905T090000 19971003T090000 19971107T090000 19971205T090000
+            19980102T090000 19980206T090000 19980306T090000 19980403T090000
+            19980501T090000 19980605T090000
+            ",
+        );
+    }
+
+    /**
+     * Monthly on the first Friday until December 24, 1997
+     */
+    #[test]
+    fn rfc_monthly_by_day_until() {
+        assert_rrule(
+            "19970905T090000",
+            "FREQ=MONTHLY;UNTIL=19971224T000000;BYDAY=1FR",
+            "
+            19970905T090000 19971003T090000 19971107T090000 19971205T090000
+            ",
+        );
+    }
+
+    /**
+     * Every other month on the first and last Sunday of the month for 10 occurrences
+     */
+    #[test]
+    fn rfc_monthly_interval_by_day() {
+        assert_rrule(
+            "19970907T090000",
+            "FREQ=MONTHLY;INTERVAL=2;COUNT=10;BYDAY=1SU,-1SU",
+            "
+            19970907T090000 19970928T090000 19971102T090000 19971130T090000
+            19980104T090000 19980125T090000 19980301T090000 19980329T090000
+            19980503T090000 19980531T090000
+            ",
+        );
+    }
+
+    /**
+     * Monthly on the second-to-last Monday of the month for 6 months
+     */
+    #[test]
+    fn rfc_monthly_by_day_negative() {
+        assert_rrule(
+            "19970922T090000",
+            "FREQ=MONTHLY;COUNT=6;BYDAY=-2MO",
+            "
+            19970922T090000 19971020T090000 19971117T090000 19971222T090000
+            19980119T090000 19980216T090000
+            ",
+        );
+    }
+
+    /**
+     * Monthly on the third-to-the-last day of the month, forever
+     */
+    #[test]
+    fn rfc_monthly_by_monthday_negative() {
+        assert_rrule(
+            "19970928T090000",
+            "FREQ=MONTHLY;BYMONTHDAY=-3",
+            "
+            19970928T090000 19971029T090000 19971128T090000 19971229T090000
+            19980129T090000 19980226T090000
+            ",
+        );
+    }
+
+    /**
+     * Monthly on the 2nd and 15th of the month for 10 occurrences
+     */
+    #[test]
+    fn rfc_monthly_by_monthday() {
+        assert_rrule(
+            "19970902T090000",
+            "FREQ=MONTHLY;COUNT=10;BYMONTHDAY=2,15",
+            "
+            19970902T090000 19970915T090000 19971002T090000 19971015T090000
+            19971102T090000 19971115T090000 19971202T090000 19971215T090000
+            19980102T090000 19980115T090000
+            ",
+        );
+    }
+
+    /**
+     * Monthly on the first and last day of the month for 10 occurrences
+     */
+    #[test]
+    fn rfc_monthly_by_monthday_first_and_last() {
+        assert_rrule(
+            "19970930T090000",
+            "FREQ=MONTHLY;COUNT=10;BYMONTHDAY=1,-1",
+            "
+            19970930T090000 19971001T090000 19971031T090000 19971101T090000
+            19971130T090000 19971201T090000 19971231T090000 19980101T090000
+            19980131T090000 19980201T090000
+            ",
+        );
+    }
+
+    /**
+     * Every 18 months on the 10th thru 15th of the month for 10 occurrences
+     */
+    #[test]
+    fn rfc_monthly_interval_by_monthday() {
+        assert_rrule(
+            "19970910T090000",
+            "FREQ=MONTHLY;INTERVAL=18;COUNT=10;BYMONTHDAY=10,11,12,13,14,15",
+            "
+            19970910T090000 19970911T090000 19970912T090000 19970913T090000
+            19970914T090000 19970915T090000 19990310T090000 19990311T090000
+            19990312T090000 19990313T090000
+            ",
+        );
+    }
+
+    /**
+     * Every Tuesday, every other month
+     */
+    #[test]
+    fn rfc_monthly_interval_every_tuesday() {
+        assert_rrule(
+            "19970902T090000",
+            "FREQ=MONTHLY;INTERVAL=2;BYDAY=TU",
+            "
+            19970902T090000 19970909T090000 19970916T090000 19970923T090000
+            19970930T090000 19971104T090000 19971111T090000 19971118T090000
+            19971125T090000 19980106T090000 19980113T090000 19980120T090000
+            19980127T090000 19980303T090000 19980310T090000 19980317T090000
+            19980324T090000 19980331T090000
+            ",
+        );
+    }
+
+    /**
+     * Yearly in June and July for 10 occurrences
+     */
+    #[test]
+    fn rfc_yearly_by_month() {
+        assert_rrule(
+            "19970610T090000",
+            "FREQ=YEARLY;COUNT=10;BYMONTH=6,7",
+            "
+            19970610T090000 19970710T090000 19980610T090000 19980710T090000
+            19990610T090000 19990710T090000 20000610T090000 20000710T090000
+            20010610T090000 20010710T090000
+            ",
+        );
+    }
+
+    /**
+     * Every other year on January, February, and March for 10 occurrences
+     */
+    #[test]
+    fn rfc_yearly_interval_by_month() {
+        assert_rrule(
+            "19970310T090000",
+            "FREQ=YEARLY;INTERVAL=2;COUNT=10;BYMONTH=1,2,3",
+            "
+            19970310T090000 19990110T090000 19990210T090000 19990310T090000
+            20010110T090000 20010210T090000 20010310T090000 20030110T090000
+            20030210T090000 20030310T090000
+            ",
+        );
+    }
+
+    /**
+     * Every third year on the 1st, 100th, and 200th day for 10 occurrences
+     */
+    #[test]
+    fn rfc_yearly_interval_by_yearday() {
+        assert_rrule(
+            "19970101T090000",
+            "FREQ=YEARLY;INTERVAL=3;COUNT=10;BYYEARDAY=1,100,200",
+            "
+            19970101T090000 19970410T090000 19970719T090000 20000101T090000
+            20000409T090000 20000718T090000 20030101T090000 20030410T090000
+            20030719T090000 20060101T090000
+            ",
+        );
+    }
+
+    /**
+     * Every 20th Monday of the year, forever
+     */
+    #[test]
+    fn rfc_yearly_by_day() {
+        assert_rrule(
+            "19970519T090000",
+            "FREQ=YEARLY;BYDAY=20MO",
+            "
+            19970519T090000 19980518T090000 19990517T090000
+            ",
+        );
+    }
+
+    /**
+     * Monday of week number 20 (where the default start of the week is Monday), forever
+     */
+    #[test]
+    fn rfc_yearly_by_weekno() {
+        assert_rrule(
+            "19970512T090000",
+            "FREQ=YEARLY;BYWEEKNO=20;BYDAY=MO",
+            "
+            19970512T090000 19980511T090000 19990517T090000
+            ",
+        );
+    }
+
+    /**
+     * Every Thursday in March, forever
+     */
+    #[test]
+    fn rfc_yearly_by_month_by_day() {
+        assert_rrule(
+            "19970313T090000",
+            "FREQ=YEARLY;BYMONTH=3;BYDAY=TH",
+            "
+            19970313T090000 19970320T090000 19970327T090000 19980305T090000
+            19980312T090000 19980319T090000 19980326T090000 19990304T090000
+            19990311T090000 19990318T090000 19990325T090000
+            ",
+        );
+    }
+
+    /**
+     * Every Thursday, but only during June, July, and August, forever
+     */
+    #[test]
+    fn rfc_yearly_by_day_by_month() {
+        assert_rrule(
+            "19970605T090000",
+            "FREQ=YEARLY;BYDAY=TH;BYMONTH=6,7,8",
+            "
+            19970605T090000 19970612T090000 19970619T090000 19970626T090000
+            19970703T090000 19970710T090000 19970717T090000 19970724T090000
+            19970731T090000 19970807T090000 19970814T090000 19970821T090000
+            19970828T090000 19980604T090000 19980611T090000 19980618T090000
+            19980625T090000 19980702T090000 19980709T090000 19980716T090000
+            19980723T090000 19980730T090000 19980806T090000 19980813T090000
+            19980820T090000 19980827T090000 19990603T090000 19990610T090000
+            19990617T090000 19990624T090000 19990701T090000 19990708T090000
+            19990715T090000 19990722T090000 19990729T090000 19990805T090000
+            19990812T090000 19990819T090000 19990826T090000
+            ",
+        );
+    }
+
+    /**
+     * Every Friday the 13th, forever
+     */
+    #[test]
+    fn rfc_monthly_friday_13th() {
+        assert_recurrence(
+            "19970902T090000",
+            "FREQ=MONTHLY;BYDAY=FR;BYMONTHDAY=13",
+            &["19970902T090000"],
+            "
+            19980213T090000 19980313T090000 19981113T090000 19990813T090000
+            20001013T090000
+            ",
+        );
+    }
+
+    /**
+     * The first Saturday that follows the first Sunday of the month, forever
+     */
+    #[test]
+    fn rfc_monthly_saturday_after_first_sunday() {
+        assert_rrule(
+            "19970913T090000",
+            "FREQ=MONTHLY;BYDAY=SA;BYMONTHDAY=7,8,9,10,11,12,13",
+            "
+            19970913T090000 19971011T090000 19971108T090000 19971213T090000
+            19980110T090000 19980207T090000 19980307T090000 19980411T090000
+            19980509T090000 19980613T090000
+            ",
+        );
+    }
+
+    /**
+     * Every 4 years, the first Tuesday after a Monday in November, forever (U.S. Presidential Election day)
+     */
+    #[test]
+    fn rfc_yearly_election_day() {
+        assert_rrule(
+            "19961105T090000",
+            "FREQ=YEARLY;INTERVAL=4;BYMONTH=11;BYDAY=TU;BYMONTHDAY=2,3,4,5,6,7,8",
+            "
+            19961105T090000 20001107T090000 20041102T090000
+            ",
+        );
+    }
+
+    /**
+     * The third instance into the month of one of Tuesday, Wednesday, or Thursday, for the next 3 months
+     */
+    #[test]
+    fn rfc_monthly_by_setpos() {
+        assert_rrule(
+            "19970904T090000",
+            "FREQ=MONTHLY;COUNT=3;BYDAY=TU,WE,TH;BYSETPOS=3",
+            "
+            19970904T090000 19971007T090000 19971106T090000
+            ",
+        );
+    }
+
+    /**
+     * The second-to-last weekday of the month
+     */
+    #[test]
+    fn rfc_monthly_by_setpos_negative() {
+        assert_rrule(
+            "19970929T090000",
+            "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-2",
+            "
+            19970929T090000 19971030T090000 19971127T090000 19971230T090000
+            19980129T090000 19980226T090000 19980330T090000
+            ",
+        );
+    }
+
+    /**
+     * Every 3 hours from 9:00 AM to 5:00 PM on a specific day
+     */
+    #[test]
+    fn rfc_hourly_interval_until() {
+        assert_rrule(
+            "19970902T090000",
+            "FREQ=HOURLY;INTERVAL=3;UNTIL=19970902T170000",
+            "
+            19970902T090000 19970902T120000 19970902T150000
+            ",
+        );
+    }
+
+    /**
+     * Every 15 minutes for 6 occurrences
+     */
+    #[test]
+    fn rfc_minutely_interval_count() {
+        assert_rrule(
+            "19970902T090000",
+            "FREQ=MINUTELY;INTERVAL=15;COUNT=6",
+            "
+            19970902T090000 19970902T091500 19970902T093000 19970902T094500
+            19970902T100000 19970902T101500
+            ",
+        );
+    }
+
+    /**
+     * Every hour and a half for 4 occurrences
+     */
+    #[test]
+    fn rfc_minutely_interval_hour_and_a_half() {
+        assert_rrule(
+            "19970902T090000",
+            "FREQ=MINUTELY;INTERVAL=90;COUNT=4",
+            "
+            19970902T090000 19970902T103000 19970902T120000 19970902T133000
+            ",
+        );
+    }
+
+    /**
+     * Every 20 minutes from 9:00 AM to 4:40 PM every day
+     */
+    #[test]
+    fn rfc_daily_by_hour_by_minute() {
+        assert_rrule(
+            "19970902T090000",
+            "FREQ=DAILY;BYHOUR=9,10,11,12,13,14,15,16;BYMINUTE=0,20,40",
+            "
+            19970902T090000 19970902T092000 19970902T094000 19970902T100000
+            19970902T102000 19970902T104000 19970902T110000 19970902T112000
+            19970902T114000 19970902T120000 19970902T122000 19970902T124000
+            19970902T130000 19970902T132000 19970902T134000 19970902T140000
+            19970902T142000 19970902T144000 19970902T150000 19970902T152000
+            19970902T154000 19970902T160000 19970902T162000 19970902T164000
+            19970903T090000 19970903T092000 19970903T094000 19970903T100000
+            19970903T102000 19970903T104000 19970903T110000 19970903T112000
+            19970903T114000 19970903T120000 19970903T122000 19970903T124000
+            19970903T130000 19970903T132000 19970903T134000 19970903T140000
+            19970903T142000 19970903T144000 19970903T150000 19970903T152000
+            19970903T154000 19970903T160000 19970903T162000 19970903T164000
+            ",
+        );
+    }
+
+    /**
+     * Every 20 minutes from 9:00 AM to 4:40 PM every day
+     */
+    #[test]
+    fn rfc_minutely_interval_by_hour() {
+        assert_rrule(
+            "19970902T090000",
+            "FREQ=MINUTELY;INTERVAL=20;BYHOUR=9,10,11,12,13,14,15,16",
+            "
+            19970902T090000 19970902T092000 19970902T094000 19970902T100000
+            19970902T102000 19970902T104000 19970902T110000 19970902T112000
+            19970902T114000 19970902T120000 19970902T122000 19970902T124000
+            19970902T130000 19970902T132000 19970902T134000 19970902T140000
+            19970902T142000 19970902T144000 19970902T150000 19970902T152000
+            19970902T154000 19970902T160000 19970902T162000 19970902T164000
+            19970903T090000 19970903T092000 19970903T094000 19970903T100000
+            19970903T102000 19970903T104000 19970903T110000 19970903T112000
+            19970903T114000 19970903T120000 19970903T122000 19970903T124000
+            19970903T130000 19970903T132000 19970903T134000 19970903T140000
+            19970903T142000 19970903T144000 19970903T150000 19970903T152000
+            19970903T154000 19970903T160000 19970903T162000 19970903T164000
+            ",
+        );
+    }
+
+    /**
+     * An example where the days generated makes a difference because of WKST
+     */
+    #[test]
+    fn rfc_weekly_wkst_monday() {
+        assert_rrule(
+            "19970805T090000",
+            "FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=MO",
+            "
+            19970805T090000 19970810T090000 19970819T090000 19970824T090000
+            ",
+        );
+    }
+
+    /**
+     * changing only WKST from MO to SU, yields different results
+     */
+    #[test]
+    fn rfc_weekly_wkst_sunday() {
+        assert_rrule(
+            "19970805T090000",
+            "FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=SU",
+            "
+            19970805T090000 19970817T090000 19970819T090000 19970831T090000
+            ",
+        );
+    }
+
+    /**
+     * An example where an invalid date (i.e., February 30) is ignored
+     */
+    #[test]
+    fn rfc_monthly_invalid_date() {
+        assert_rrule(
+            "20070115T090000",
+            "FREQ=MONTHLY;BYMONTHDAY=15,30;COUNT=5",
+            "
+            20070115T090000 20070130T090000 20070215T090000 20070315T090000
+            20070330T090000
+            ",
+        );
+    }
+}
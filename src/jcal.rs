@@ -0,0 +1,587 @@
+/*!
+ * The JSON format for iCalendar.
+ *
+ * A value is converted through its iCalendar serialization, so the
+ * x-properties, iana-properties and unknown components are kept like with
+ * the ICS format.
+ *
+ * Requires the `jcal` feature.
+ *
+ * ```
+ * let vcalendar = ikal::VCalendar {
+ *     version: "2.0".into(),
+ *     prodid: "-//ikal//jcal//EN".into(),
+ *
+ *     ..Default::default()
+ * };
+ *
+ * let jcal = ikal::jcal::to_string(&vcalendar)?;
+ * assert_eq!(
+ *     jcal,
+ *     r#"["vcalendar",[["prodid",{},"text","-//ikal//jcal//EN"],["version",{},"text","2.0"]],[]]"#
+ * );
+ *
+ * assert_eq!(ikal::jcal::from_str::<ikal::VCalendar>(&jcal)?, vcalendar);
+ * # Ok::<(), ikal::Error>(())
+ * ```
+ *
+ * See [RFC 7265](https://datatracker.ietf.org/doc/html/rfc7265)
+ */
+
+use serde_json::Value;
+
+/**
+ * Converts a component to its jCal representation.
+ */
+pub fn to_value<T: crate::ser::Serialize>(value: &T) -> crate::Result<Value> {
+    let raw = crate::ser::ical(value)?.parse::<crate::RawComponent>()?;
+
+    Ok(component(&raw))
+}
+
+/**
+ * Same as [`to_value`] but returns the JSON text.
+ */
+pub fn to_string<T: crate::ser::Serialize>(value: &T) -> crate::Result<String> {
+    serde_json::to_string(&to_value(value)?).map_err(|err| crate::Error::JCal(err.to_string()))
+}
+
+/**
+ * Converts a jCal component, like a `vcalendar`, to `T`.
+ */
+pub fn from_value<T: TryFrom<String, Error = crate::Error>>(value: &Value) -> crate::Result<T> {
+    let raw = raw(value)?;
+
+    crate::ser::ical(&raw)?.try_into()
+}
+
+/**
+ * Same as [`from_value`] but parses the JSON text first.
+ */
+pub fn from_str<T: TryFrom<String, Error = crate::Error>>(s: &str) -> crate::Result<T> {
+    let value = serde_json::from_str(s).map_err(|err| crate::Error::JCal(err.to_string()))?;
+
+    from_value(&value)
+}
+
+/**
+ * The value type of a property without `VALUE` parameter.
+ *
+ * See [3.8. Component Properties](https://datatracker.ietf.org/doc/html/rfc5545#section-3.8)
+ */
+fn default_type(name: &str) -> &'static str {
+    match name {
+        "ATTACH" | "TZURL" | "URL" => "uri",
+        "ATTENDEE" | "ORGANIZER" => "cal-address",
+        "COMPLETED" | "CREATED" | "DTEND" | "DTSTAMP" | "DTSTART" | "DUE" | "EXDATE"
+        | "LAST-MODIFIED" | "RDATE" | "RECURRENCE-ID" => "date-time",
+        "DURATION" | "TRIGGER" => "duration",
+        "FREEBUSY" => "period",
+        "GEO" => "float",
+        "PERCENT-COMPLETE" | "PRIORITY" | "REPEAT" | "SEQUENCE" => "integer",
+        "RRULE" => "recur",
+        "TZOFFSETFROM" | "TZOFFSETTO" => "utc-offset",
+        "ACTION" | "CALSCALE" | "CATEGORIES" | "CLASS" | "COMMENT" | "CONTACT" | "DESCRIPTION"
+        | "LOCATION" | "METHOD" | "PRODID" | "RELATED-TO" | "REQUEST-STATUS" | "RESOURCES"
+        | "STATUS" | "SUMMARY" | "TRANSP" | "TZID" | "TZNAME" | "UID" | "VERSION" => "text",
+        _ => "unknown",
+    }
+}
+
+/**
+ * The value types with a comma separated list of values.
+ */
+fn is_list(name: &str, ty: &str) -> bool {
+    match ty {
+        "text" => matches!(name, "CATEGORIES" | "RESOURCES"),
+        "float" => name != "GEO",
+        "boolean" | "date" | "date-time" | "duration" | "integer" | "period" | "time"
+        | "utc-offset" => true,
+        _ => false,
+    }
+}
+
+/**
+ * The recurrence rule parts with a numeric value.
+ */
+const NUMERIC_PARTS: [&str; 10] = [
+    "count",
+    "interval",
+    "bysecond",
+    "byminute",
+    "byhour",
+    "bymonthday",
+    "byyearday",
+    "byweekno",
+    "bymonth",
+    "bysetpos",
+];
+
+fn component(raw: &crate::RawComponent) -> Value {
+    let properties = raw.properties.iter().map(property).collect();
+    let components = raw.components.iter().map(component).collect();
+
+    Value::Array(vec![
+        Value::String(raw.name.to_lowercase()),
+        Value::Array(properties),
+        Value::Array(components),
+    ])
+}
+
+/**
+ * See [3.4. Properties](https://datatracker.ietf.org/doc/html/rfc7265#section-3.4)
+ */
+fn property(content_line: &crate::ContentLine) -> Value {
+    let name = content_line.key.to_uppercase();
+    let mut params = content_line.params.clone();
+
+    let ty = params
+        .remove("VALUE")
+        .and_then(|x| x.into_iter().next())
+        .map_or_else(|| default_type(&name).to_string(), |x| x.to_lowercase());
+
+    let params = params
+        .iter()
+        .map(|(name, values)| {
+            let value = match values.as_slice() {
+                [value] => Value::String(value.clone()),
+                values => values.iter().cloned().map(Value::String).collect(),
+            };
+
+            (name.to_lowercase(), value)
+        })
+        .collect();
+
+    let mut property = vec![
+        Value::String(name.to_lowercase()),
+        Value::Object(params),
+        Value::String(ty.clone()),
+    ];
+
+    let raw = &content_line.value;
+
+    match (name.as_str(), ty.as_str()) {
+        ("GEO", "float") => property.push(
+            split(raw, ';')
+                .into_iter()
+                .map(|x| number(x, "float"))
+                .collect(),
+        ),
+        ("REQUEST-STATUS", "text") => property.push(
+            split(raw, ';')
+                .into_iter()
+                .map(|x| Value::String(crate::parser::datatype::unescape(x)))
+                .collect(),
+        ),
+        (_, "recur") => property.push(recur(raw)),
+        _ if is_list(&name, &ty) => {
+            property.extend(split(raw, ',').into_iter().map(|x| value(x, &ty)));
+        }
+        _ => property.push(value(raw, &ty)),
+    }
+
+    Value::Array(property)
+}
+
+/**
+ * Splits `s` on the unescaped `sep`.
+ */
+fn split(s: &str, sep: char) -> Vec<&str> {
+    let mut values = Vec::new();
+    let mut start = 0;
+    let mut chars = s.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        if c == '\\' {
+            chars.next();
+        } else if c == sep {
+            values.push(&s[start..i]);
+            start = i + 1;
+        }
+    }
+    values.push(&s[start..]);
+
+    values
+}
+
+/**
+ * See [3.5. Values](https://datatracker.ietf.org/doc/html/rfc7265#section-3.5)
+ */
+fn value(s: &str, ty: &str) -> Value {
+    match ty {
+        "boolean" => match s.to_uppercase().as_str() {
+            "TRUE" => Value::Bool(true),
+            "FALSE" => Value::Bool(false),
+            _ => Value::String(s.to_string()),
+        },
+        "date" | "date-time" => Value::String(date_time(s)),
+        "float" | "integer" => number(s, ty),
+        "period" => {
+            let period = s
+                .split('/')
+                .map(|x| {
+                    if x.contains('P') {
+                        x.to_string()
+                    } else {
+                        date_time(x)
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            Value::String(period.join("/"))
+        }
+        "text" => Value::String(crate::parser::datatype::unescape(s)),
+        "time" => Value::String(time(s)),
+        "utc-offset" => Value::String(utc_offset(s)),
+        _ => Value::String(s.to_string()),
+    }
+}
+
+fn number(s: &str, ty: &str) -> Value {
+    let number = if ty == "integer" {
+        s.parse::<i64>().ok().map(serde_json::Number::from)
+    } else {
+        s.parse::<f64>().ok().and_then(serde_json::Number::from_f64)
+    };
+
+    number.map_or_else(|| Value::String(s.to_string()), Value::Number)
+}
+
+/**
+ * `19970714T173000Z` becomes `1997-07-14T17:30:00Z` and `19970714` becomes
+ * `1997-07-14`.
+ */
+fn date_time(s: &str) -> String {
+    if s.len() < 8 || !s.is_ascii() {
+        return s.to_string();
+    }
+
+    let (date, time) = s.split_at(8);
+    let date = format!("{}-{}-{}", &date[..4], &date[4..6], &date[6..]);
+
+    match time.strip_prefix('T') {
+        Some(t) => format!("{date}T{}", self::time(t)),
+        None => date,
+    }
+}
+
+/**
+ * `173000Z` becomes `17:30:00Z`.
+ */
+fn time(s: &str) -> String {
+    if s.len() < 6 || !s.is_ascii() {
+        return s.to_string();
+    }
+
+    format!("{}:{}:{}", &s[..2], &s[2..4], &s[4..])
+}
+
+/**
+ * `-0500` becomes `-05:00`.
+ */
+fn utc_offset(s: &str) -> String {
+    if s.len() < 5 || !s.is_ascii() {
+        return s.to_string();
+    }
+
+    let mut offset = format!("{}:{}", &s[..3], &s[3..5]);
+    if s.len() > 5 {
+        offset.push(':');
+        offset.push_str(&s[5..]);
+    }
+
+    offset
+}
+
+/**
+ * See [3.6.10. Recurrence Rule](https://datatracker.ietf.org/doc/html/rfc7265#section-3.6.10)
+ */
+fn recur(s: &str) -> Value {
+    let rule = s
+        .split(';')
+        .filter_map(|part| part.split_once('='))
+        .map(|(name, value)| {
+            let name = name.to_lowercase();
+
+            let values = value
+                .split(',')
+                .map(|x| {
+                    if name == "until" {
+                        Value::String(date_time(x))
+                    } else if NUMERIC_PARTS.contains(&name.as_str()) {
+                        number(x, "integer")
+                    } else {
+                        Value::String(x.to_string())
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            let value = match <[_; 1]>::try_from(values) {
+                Ok([value]) => value,
+                Err(values) => Value::Array(values),
+            };
+
+            (name, value)
+        })
+        .collect();
+
+    Value::Object(rule)
+}
+
+fn raw(value: &Value) -> crate::Result<crate::RawComponent> {
+    let Some(
+        [
+            Value::String(name),
+            Value::Array(properties),
+            Value::Array(components),
+        ],
+    ) = value.as_array().map(Vec::as_slice)
+    else {
+        return Err(invalid("component", value));
+    };
+
+    Ok(crate::RawComponent {
+        name: name.to_uppercase(),
+        properties: properties
+            .iter()
+            .map(content_line)
+            .collect::<crate::Result<_>>()?,
+        components: components.iter().map(raw).collect::<crate::Result<_>>()?,
+    })
+}
+
+fn content_line(value: &Value) -> crate::Result<crate::ContentLine> {
+    let Some(
+        [
+            Value::String(name),
+            Value::Object(params),
+            Value::String(ty),
+            values @ ..,
+        ],
+    ) = value.as_array().map(Vec::as_slice)
+    else {
+        return Err(invalid("property", value));
+    };
+
+    let key = name.to_uppercase();
+    let mut content_line = crate::ContentLine {
+        key: key.clone(),
+        params: crate::Params::new(),
+        value: String::new(),
+    };
+
+    for (name, value) in params {
+        let values = match value {
+            Value::Array(values) => values.as_slice(),
+            value => std::slice::from_ref(value),
+        };
+
+        for value in values {
+            let Value::String(value) = value else {
+                return Err(invalid("parameter value", value));
+            };
+
+            content_line
+                .params
+                .append(name.to_uppercase(), value.clone());
+        }
+    }
+
+    if ty != "unknown" && ty != default_type(&key) {
+        content_line
+            .params
+            .insert("VALUE".to_string(), ty.to_uppercase());
+    }
+
+    content_line.value = values
+        .iter()
+        .map(|x| ical(x, ty))
+        .collect::<crate::Result<Vec<_>>>()?
+        .join(",");
+
+    Ok(content_line)
+}
+
+/**
+ * The iCalendar representation of a jCal value.
+ */
+fn ical(value: &Value, ty: &str) -> crate::Result<String> {
+    let s = match value {
+        Value::Bool(true) => "TRUE".to_string(),
+        Value::Bool(false) => "FALSE".to_string(),
+        Value::Number(number) => number.to_string(),
+        Value::String(s) => match ty {
+            "date" | "date-time" | "time" => s.replace(['-', ':'], ""),
+            "period" => s
+                .split('/')
+                .map(|x| {
+                    if x.contains('P') {
+                        x.to_string()
+                    } else {
+                        x.replace(['-', ':'], "")
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("/"),
+            "text" => crate::ser::escape(s),
+            "utc-offset" => s.replace(':', ""),
+            _ => s.clone(),
+        },
+        Value::Array(values) => values
+            .iter()
+            .map(|x| ical(x, ty))
+            .collect::<crate::Result<Vec<_>>>()?
+            .join(";"),
+        Value::Object(rule) if ty == "recur" => {
+            let mut parts = rule.iter().collect::<Vec<_>>();
+            parts.sort_by_key(|(name, _)| *name != "freq");
+
+            parts
+                .into_iter()
+                .map(|(name, value)| {
+                    let ty = if name == "until" { "date-time" } else { "" };
+                    let values = match value {
+                        Value::Array(values) => values.as_slice(),
+                        value => std::slice::from_ref(value),
+                    };
+
+                    let values = values
+                        .iter()
+                        .map(|x| ical(x, ty))
+                        .collect::<crate::Result<Vec<_>>>()?;
+
+                    Ok(format!("{}={}", name.to_uppercase(), values.join(",")))
+                })
+                .collect::<crate::Result<Vec<_>>>()?
+                .join(";")
+        }
+        value => return Err(invalid("value", value)),
+    };
+
+    Ok(s)
+}
+
+fn invalid(expected: &str, found: &Value) -> crate::Error {
+    crate::Error::JCal(format!("expected {expected}, found {found}"))
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn to_value() -> crate::Result {
+        let vevent = crate::VEvent::try_from(
+            "BEGIN:VEVENT\r
+UID:4088E990AD89CB3DBB484909\r
+DTSTAMP:20080205T191224Z\r
+DTSTART;VALUE=DATE:20081006\r
+DURATION:P1D\r
+SUMMARY:Planning meeting\\, room 2\r
+CATEGORIES:MEETING,PLANNING\r
+GEO:37.386013;-122.082932\r
+RRULE:FREQ=YEARLY;UNTIL=20200101T000000Z;BYDAY=1SU;BYMONTH=4\r
+ATTENDEE;PARTSTAT=ACCEPTED;MEMBER=\"mailto:a@example.com\",\"mailto:b@example.com\":mailto:jdoe@example.com\r
+REQUEST-STATUS:2.0;Success\r
+X-ANSWER;VALUE=INTEGER:42\r
+X-LIC-LOCATION:Europe/Paris\r
+END:VEVENT\r
+",
+        )?;
+
+        let jcal = super::to_value(&vevent)?;
+
+        similar_asserts::assert_eq!(
+            jcal,
+            serde_json::json!([
+                "vevent",
+                [
+                    ["dtstamp", {}, "date-time", "2008-02-05T19:12:24Z"],
+                    ["uid", {}, "text", "4088E990AD89CB3DBB484909"],
+                    ["dtstart", {}, "date", "2008-10-06"],
+                    ["geo", {}, "float", [37.386013, -122.08293]],
+                    ["summary", {}, "text", "Planning meeting, room 2"],
+                    ["rrule", {}, "recur", {
+                        "freq": "YEARLY",
+                        "until": "2020-01-01T00:00:00Z",
+                        "interval": 1,
+                        "byday": "1SU",
+                        "bymonth": 4,
+                    }],
+                    ["duration", {}, "duration", "P1D"],
+                    ["attendee", {
+                        "member": ["mailto:a@example.com", "mailto:b@example.com"],
+                        "partstat": "ACCEPTED",
+                    }, "cal-address", "mailto:jdoe@example.com"],
+                    ["categories", {}, "text", "MEETING", "PLANNING"],
+                    ["x-answer", {}, "integer", 42],
+                    ["x-lic-location", {}, "unknown", "Europe/Paris"],
+                    ["request-status", {}, "text", ["2.0", "Success"]],
+                ],
+                [],
+            ])
+        );
+
+        let round_trip = super::from_value::<crate::VEvent>(&jcal)?;
+        assert_eq!(round_trip, vevent);
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_str() -> crate::Result {
+        let jcal = r#"["vcalendar",
+            [["version", {}, "text", "2.0"], ["prodid", {}, "text", "-//ikal//jcal//EN"]],
+            [["vtimezone",
+                [["tzid", {}, "text", "Europe/Paris"]],
+                [["standard", [
+                    ["dtstart", {}, "date-time", "1970-10-25T03:00:00"],
+                    ["tzoffsetfrom", {}, "utc-offset", "+02:00"],
+                    ["tzoffsetto", {}, "utc-offset", "+01:00"]
+                ], []]]
+            ],
+            ["vevent", [
+                ["uid", {}, "text", "1"],
+                ["dtstamp", {}, "date-time", "2024-01-01T00:00:00Z"],
+                ["dtstart", {"tzid": "Europe/Paris"}, "date-time", "2024-01-01T09:00:00"],
+                ["exdate", {}, "date", "2024-01-02", "2024-01-03"],
+                ["rdate", {}, "period", "2024-01-05T14:00:00/PT3H"]
+            ], []]]
+        ]"#;
+
+        let vcalendar = super::from_str::<crate::VCalendar>(jcal)?;
+        let vevent = &vcalendar.events[0];
+
+        assert_eq!(vevent.dtstart.tzid(), Some("Europe/Paris"));
+        assert_eq!(
+            vevent.exdate,
+            ["20240102".parse()?, "20240103".parse::<crate::Date>()?]
+        );
+        assert_eq!(
+            vevent.rdate,
+            [crate::RDate::Period(vec!["20240105T140000/PT3H".parse()?])]
+        );
+        assert_eq!(
+            vcalendar.timezones[0].standard[0].tzoffsetto,
+            chrono::FixedOffset::east_opt(3_600).unwrap()
+        );
+
+        assert!(super::from_str::<crate::VCalendar>(r#"["vcalendar", []]"#).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip() -> crate::Result {
+        for (file, vcalendar) in crate::test::calendars() {
+            let jcal = super::to_string(&vcalendar)?;
+            let round_trip = super::from_str::<crate::VCalendar>(&jcal)?;
+
+            similar_asserts::assert_eq!(
+                crate::ser::ical(&round_trip)?,
+                crate::ser::ical(&vcalendar)?,
+                "{file:?}"
+            );
+        }
+
+        Ok(())
+    }
+}
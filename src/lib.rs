@@ -1,7 +1,13 @@
 #![warn(warnings)]
 
 pub mod iter;
+#[cfg(feature = "jcal")]
+pub mod jcal;
+pub mod reader;
 pub mod ser;
+pub mod slots;
+#[cfg(feature = "xcal")]
+pub mod xcal;
 
 mod components;
 mod content_line;
@@ -106,7 +112,7 @@ pub use ikal_derive::{Component, Serialize};
 #[doc(hidden)]
 pub use ikal_derive::{audio, display, email};
 
-use content_line::*;
+pub use content_line::*;
 
 #[macro_export]
 /**
@@ -122,68 +128,8 @@ macro_rules! valarm {
     };
 }
 
-#[doc(hidden)]
-pub fn parse_duration(value: &str) -> crate::Result<chrono::TimeDelta> {
-    let mut duration = chrono::TimeDelta::default();
-    let mut it = value.chars().peekable();
-
-    let mut negative = false;
-    let mut time = false;
-    let mut interval = String::new();
-
-    if it.next_if(|x| *x == '-').is_some() {
-        negative = true;
-    }
-
-    if it.next_if(|x| *x == 'P').is_none() {
-        return Err(crate::Error::ParseDuration(format!(
-            "Invalid duration: {value}"
-        )));
-    };
-
-    loop {
-        if it.next_if(|x| *x == 'T').is_some() {
-            time = true;
-            continue;
-        }
-
-        if let Some(x) = it.next_if(|x| x.is_ascii_digit()) {
-            interval.push(x);
-            continue;
-        };
-
-        let Some(ty) = it.next() else {
-            break;
-        };
-
-        let part = match (time, ty) {
-            (false, 'Y') => chrono::TimeDelta::days(365 * interval.parse::<i64>()?),
-            (false, 'M') => chrono::TimeDelta::days(30 * interval.parse::<i64>()?),
-            (false, 'D') => chrono::TimeDelta::days(interval.parse()?),
-            (true, 'H') => chrono::TimeDelta::hours(interval.parse()?),
-            (true, 'M') => chrono::TimeDelta::minutes(interval.parse()?),
-            (true, 'S') => chrono::TimeDelta::seconds(interval.parse()?),
-            _ => {
-                return Err(crate::Error::ParseDuration(format!(
-                    "Invalid duration: {interval}"
-                )));
-            }
-        };
-
-        duration += part;
-    }
-
-    if negative {
-        duration = -duration;
-    }
-
-    Ok(duration)
-}
-
 #[cfg(test)]
 mod test {
-    use std::collections::BTreeMap;
-
     #[test]
     fn test_content_line() {
         let line = "VERSION:2.0
@@ -199,15 +145,35 @@ PRODID:-//Nextcloud calendar v1.5.0";
     }
 
     #[test]
-    fn test_binary_content() {
-        // @TODO https://tools.ietf.org/html/rfc2445#section-4.1.3
+    fn test_binary_content() -> crate::Result {
+        let line = "ATTACH;FMTTYPE=image/png;ENCODING=BASE64;VALUE=BINARY:iVBORw0KGgo=\r\n";
+
+        let (_, content_line) = crate::parser::content_line(line)?;
+        let attach = crate::parser::attach(content_line)?;
+
+        let crate::Attachment::Binary(binary) = &attach else {
+            panic!("Binary attachment expected");
+        };
+        assert_eq!(binary.data, b"\x89PNG\r\n\x1a\n");
+        assert_eq!(attach.fmttype(), Some("image/png"));
+        assert!(binary.params.is_empty());
+        assert_eq!(
+            crate::ser::ical(&attach)?,
+            "ENCODING=BASE64;FMTTYPE=image/png;VALUE=BINARY:iVBORw0KGgo="
+        );
+
+        let (_, content_line) =
+            crate::parser::content_line("ATTACH;ENCODING=BASE64;VALUE=BINARY:not base64\r\n")?;
+        assert!(crate::parser::attach(content_line).is_err());
+
+        Ok(())
     }
 
     #[test]
     fn test_param() {
         let line = "CREATED;VALUE=DATE-TIME:20141009T141617Z\r\n";
 
-        let mut params = BTreeMap::new();
+        let mut params = crate::Params::new();
         params.insert("VALUE".to_string(), "DATE-TIME".to_string());
         let expected = vec![crate::ContentLine {
             key: "CREATED".to_string(),
@@ -218,6 +184,33 @@ PRODID:-//Nextcloud calendar v1.5.0";
         assert_eq!(crate::parser::content_lines(line), Ok(("", expected)));
     }
 
+    #[test]
+    fn test_multi_valued_param() -> crate::Result {
+        let line = "ATTENDEE;MEMBER=\"mailto:a@example.com\",\"mailto:b@example.com\";DELEGATED-TO=\"mailto:c@example.com\";X-LIST=a,b;X-LIST=c;CN=\"Doe, John\";X-NOTE=^'ikal^'^nline ^^2:mailto:d@example.com\r\n";
+
+        let (_, content_line) = crate::parser::content_line(line)?;
+
+        assert_eq!(
+            content_line.params.get_all("MEMBER"),
+            ["mailto:a@example.com", "mailto:b@example.com"]
+        );
+        assert_eq!(
+            content_line.params.get("DELEGATED-TO").map(String::as_str),
+            Some("mailto:c@example.com")
+        );
+        assert_eq!(content_line.params.get_all("X-LIST"), ["a", "b", "c"]);
+        assert_eq!(content_line.params.get_all("CN"), ["Doe, John"]);
+        assert_eq!(content_line.params.get_all("X-NOTE"), ["\"ikal\"\nline ^2"]);
+        assert_eq!(content_line.value, "mailto:d@example.com");
+
+        similar_asserts::assert_eq!(
+            crate::ser::ical(&content_line)?,
+            "CN=\"Doe, John\";DELEGATED-TO=\"mailto:c@example.com\";MEMBER=\"mailto:a@example.com\",\"mailto:b@example.com\";X-LIST=a,b,c;X-NOTE=^'ikal^'^nline ^^2:mailto:d@example.com"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_content_lines() {
         let line = "VERSION:2.0
@@ -282,4 +275,52 @@ CALSCALE:GREGORIAN
             }
         }
     }
+
+    /**
+     * Parses the calendars of `tests/calendars`, ordered by file name.
+     *
+     * Only the known invalid files, without `.out`, may fail to parse.
+     */
+    #[cfg(any(feature = "jcal", feature = "serde"))]
+    pub(crate) fn calendars() -> Vec<(std::path::PathBuf, crate::VCalendar)> {
+        const INVALID: [&str; 13] = [
+            "big_bad_calendar.ics",
+            "broken_ical.ics",
+            "issue_104_broken_calendar.ics",
+            "issue_165_missing_event.ics",
+            "issue_168_expected_output.ics",
+            "issue_168_input.ics",
+            "issue_178_component_with_invalid_name_represented.ics",
+            "issue_178_custom_component_contains_other.ics",
+            "issue_178_custom_component_inside_other.ics",
+            "issue_348_exception_parsing_value.ics",
+            "multiple_calendar_components.ics",
+            "pr_480_summary_with_colon.ics",
+            "small_bad_calendar.ics",
+        ];
+
+        let mut files = std::path::Path::new("tests/calendars")
+            .read_dir()
+            .expect("Unable to open tests dir")
+            .map(|entry| entry.unwrap().path())
+            .filter(|file| file.extension() == Some(std::ffi::OsStr::new("ics")))
+            .collect::<Vec<_>>();
+        files.sort();
+
+        let mut calendars = Vec::new();
+        let mut invalid = Vec::new();
+
+        for file in files {
+            let input = std::fs::read_to_string(&file).unwrap();
+
+            match crate::VCalendar::try_from(input) {
+                Ok(vcalendar) => calendars.push((file, vcalendar)),
+                Err(_) => invalid.push(file.file_name().unwrap().to_string_lossy().to_string()),
+            }
+        }
+
+        assert_eq!(invalid, INVALID);
+
+        calendars
+    }
 }
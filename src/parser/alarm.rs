@@ -6,7 +6,7 @@
  * See [3.8.6.1. Action](https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.6.1)
  */
 pub(crate) fn action(input: crate::ContentLine) -> crate::Result<crate::Text> {
-    Ok(input.into())
+    super::datatype::text(input)
 }
 
 /**
@@ -22,10 +22,13 @@ pub(crate) fn repeat(input: crate::ContentLine) -> crate::Result<u32> {
 pub(crate) fn trigger(input: crate::ContentLine) -> crate::Result<crate::Trigger> {
     use nom::Parser as _;
     use nom::branch::alt;
-    use nom::combinator::map;
+    use nom::combinator::{all_consuming, map};
 
     alt((
-        map(super::datatype::duration, crate::Trigger::Duration),
+        map(
+            all_consuming(super::datatype::duration),
+            crate::Trigger::Duration,
+        ),
         map(super::datatype::date_time, crate::Trigger::DateTime),
     ))
     .parse(input.value.as_str())
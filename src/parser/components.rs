@@ -1,10 +1,73 @@
 use nom::Parser as _;
 use nom::branch::alt;
-use nom::bytes::complete::tag;
-use nom::combinator::{map, map_res};
+use nom::bytes::complete::{tag, take_till};
+use nom::character::complete::line_ending;
+use nom::combinator::{map, map_res, verify};
 use nom::error::{FromExternalError, context};
 use nom::multi::many0;
-use nom::sequence::delimited;
+use nom::sequence::{delimited, terminated};
+
+/**
+ * `BEGIN:name` followed by any line ending.
+ */
+fn begin<'a>(name: &str) -> impl FnMut(&'a str) -> super::NomResult<&'a str, &'a str> {
+    let line = format!("BEGIN:{name}");
+
+    move |input| terminated(tag(line.as_str()), line_ending).parse(input)
+}
+
+/**
+ * `END:name` followed by any line ending.
+ */
+fn end<'a>(name: &str) -> impl FnMut(&'a str) -> super::NomResult<&'a str, &'a str> {
+    let line = format!("END:{name}");
+
+    move |input| terminated(tag(line.as_str()), line_ending).parse(input)
+}
+
+/**
+ * Names of the components known by this crate, the others are kept as
+ * [`crate::RawComponent`].
+ */
+pub(crate) const COMPONENTS: [&str; 9] = [
+    "VCALENDAR",
+    "VEVENT",
+    "VTODO",
+    "VJOURNAL",
+    "VFREEBUSY",
+    "VTIMEZONE",
+    "VALARM",
+    "STANDARD",
+    "DAYLIGHT",
+];
+
+/**
+ * Any component, parsed as a [`crate::RawComponent`].
+ */
+pub(crate) fn raw(input: &str) -> super::NomResult<&str, crate::RawComponent> {
+    let (input, name) = context(
+        "raw",
+        delimited(tag("BEGIN:"), take_till(super::is_line_ending), line_ending),
+    )
+    .parse(input)?;
+
+    map(
+        terminated((super::content_lines, many0(raw)), end(name)),
+        |(properties, components)| crate::RawComponent {
+            name: name.to_string(),
+            properties,
+            components,
+        },
+    )
+    .parse(input)
+}
+
+/**
+ * A component unknown to this crate.
+ */
+fn unknown(input: &str) -> super::NomResult<&str, crate::RawComponent> {
+    verify(raw, |x| !COMPONENTS.contains(&x.name.as_str())).parse(input)
+}
 
 macro_rules! component {
     ($name:ident, $ty:ty) => {
@@ -12,12 +75,16 @@ macro_rules! component {
             let c = stringify!($name).to_uppercase();
 
             map_res(
-                delimited(
-                    tag(format!("BEGIN:{c}\r\n").as_str()),
-                    super::content_lines,
-                    tag(format!("END:{c}\r\n").as_str()),
-                ),
-                |values| values.try_into(),
+                delimited(begin(&c), (super::content_lines, many0(unknown)), end(&c)),
+                |(values, components)| {
+                    let mut component: $ty = values.try_into()?;
+
+                    for raw in components {
+                        component.push_raw(raw);
+                    }
+
+                    Ok::<_, crate::Error>(component)
+                },
             )
             .parse(input)
         }
@@ -48,13 +115,26 @@ pub(crate) fn vevent(input: &str) -> super::NomResult<&str, crate::VEvent> {
         "vevent",
         map_res(
             delimited(
-                tag("BEGIN:VEVENT\r\n"),
-                (super::content_lines, many0(valarm)),
-                tag("END:VEVENT\r\n"),
+                begin("VEVENT"),
+                (
+                    super::content_lines,
+                    many0(alt((
+                        map(valarm, crate::Component::Alarm),
+                        map(unknown, crate::Component::Raw),
+                    ))),
+                ),
+                end("VEVENT"),
             ),
-            |(content_lines, alarms)| {
+            |(content_lines, components)| {
                 let mut vevent: crate::VEvent = content_lines.try_into()?;
-                vevent.alarms = alarms;
+
+                for component in components {
+                    match component {
+                        crate::Component::Alarm(alarm) => vevent.alarms.push(alarm),
+                        crate::Component::Raw(raw) => vevent.push_raw(raw),
+                        _ => unreachable!(),
+                    }
+                }
 
                 Ok::<_, crate::Error>(vevent)
             },
@@ -68,15 +148,16 @@ pub(crate) fn vtimezone(input: &str) -> super::NomResult<&str, crate::VTimezone>
         "vtimezone",
         map_res(
             delimited(
-                tag("BEGIN:VTIMEZONE\r\n"),
+                begin("VTIMEZONE"),
                 (
                     super::content_lines,
                     many0(alt((
                         map(standard, crate::vtimezone::Component::Standard),
                         map(daylight, crate::vtimezone::Component::Daylight),
+                        map(unknown, crate::vtimezone::Component::Raw),
                     ))),
                 ),
-                tag("END:VTIMEZONE\r\n"),
+                end("VTIMEZONE"),
             ),
             |(values, components)| {
                 let mut vtimezone: crate::VTimezone = values.try_into()?;
@@ -89,6 +170,7 @@ pub(crate) fn vtimezone(input: &str) -> super::NomResult<&str, crate::VTimezone>
                         crate::vtimezone::Component::Daylight(daylight) => {
                             vtimezone.daylight.push(daylight);
                         }
+                        crate::vtimezone::Component::Raw(raw) => vtimezone.push_raw(raw),
                     }
                 }
 
@@ -107,6 +189,7 @@ pub(crate) fn component(input: &str) -> super::NomResult<&str, crate::Component>
             map(vevent, crate::Component::Event),
             map(vfreebusy, crate::Component::Freebusy),
             map(vjournal, crate::Component::Journal),
+            map(unknown, crate::Component::Raw),
             map(vtimezone, crate::Component::Timezone),
             map(vtodo, crate::Component::Todo),
         )),
@@ -123,7 +206,7 @@ pub(crate) fn vcalendar(input: &str) -> super::NomResult<&str, crate::VCalendar>
         "vcalendar",
         map_res(
             delimited(
-                tag("BEGIN:VCALENDAR\r\n"),
+                begin("VCALENDAR"),
                 (super::content_lines, components),
                 tag("END:VCALENDAR"),
             ),
@@ -142,6 +225,7 @@ pub(crate) fn vcalendar(input: &str) -> super::NomResult<&str, crate::VCalendar>
                         crate::Component::Event(event) => vcalendar.events.push(event),
                         crate::Component::Freebusy(freebusy) => vcalendar.freebusy.push(freebusy),
                         crate::Component::Journal(journal) => vcalendar.journals.push(journal),
+                        crate::Component::Raw(raw) => vcalendar.push_raw(raw),
                         crate::Component::Todo(todo) => vcalendar.todo.push(todo),
                         crate::Component::Timezone(timezone) => vcalendar.timezones.push(timezone),
                     }
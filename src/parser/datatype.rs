@@ -46,10 +46,7 @@ pub(crate) fn date_time(input: &str) -> super::NomResult<&str, crate::DateTime>
         })?;
 
     if date.ends_with('Z') {
-        Ok((
-            "",
-            crate::DateTime::Local(dt.and_utc().with_timezone(&chrono::Local)),
-        ))
+        Ok(("", crate::DateTime::Utc(dt.and_utc())))
     } else {
         Ok(("", crate::DateTime::Naive(dt)))
     }
@@ -69,8 +66,8 @@ pub(crate) fn date_or_dt(input: &str) -> super::NomResult<&str, crate::Date> {
 /**
  * See [3.3.6. Duration](https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.6)
  */
-pub(crate) fn duration(input: &str) -> super::NomResult<&str, chrono::Duration> {
-    fn week(input: &str) -> super::NomResult<&str, i64> {
+pub(crate) fn duration(input: &str) -> super::NomResult<&str, crate::Duration> {
+    fn week(input: &str) -> super::NomResult<&str, u32> {
         context(
             "week",
             map_res(terminated(super::digits, tag("W")), str::parse),
@@ -78,7 +75,7 @@ pub(crate) fn duration(input: &str) -> super::NomResult<&str, chrono::Duration>
         .parse(input)
     }
 
-    fn day(input: &str) -> super::NomResult<&str, i64> {
+    fn day(input: &str) -> super::NomResult<&str, u32> {
         context(
             "day",
             map_res(terminated(super::digits, tag("D")), str::parse),
@@ -86,7 +83,7 @@ pub(crate) fn duration(input: &str) -> super::NomResult<&str, chrono::Duration>
         .parse(input)
     }
 
-    fn time(input: &str) -> super::NomResult<&str, (i64, i64, i64)> {
+    fn time(input: &str) -> super::NomResult<&str, (u32, u32, u32)> {
         let (input, (h, i, s)) = context(
             "time",
             preceded(tag("T"), (opt(hour), opt(minute), opt(seconde))),
@@ -103,7 +100,7 @@ pub(crate) fn duration(input: &str) -> super::NomResult<&str, chrono::Duration>
         ))
     }
 
-    fn hour(input: &str) -> super::NomResult<&str, i64> {
+    fn hour(input: &str) -> super::NomResult<&str, u32> {
         context(
             "hour",
             map_res(terminated(super::digits, tag("H")), str::parse),
@@ -111,7 +108,7 @@ pub(crate) fn duration(input: &str) -> super::NomResult<&str, chrono::Duration>
         .parse(input)
     }
 
-    fn minute(input: &str) -> super::NomResult<&str, i64> {
+    fn minute(input: &str) -> super::NomResult<&str, u32> {
         context(
             "minute",
             map_res(terminated(super::digits, tag("M")), str::parse),
@@ -119,7 +116,7 @@ pub(crate) fn duration(input: &str) -> super::NomResult<&str, chrono::Duration>
         .parse(input)
     }
 
-    fn seconde(input: &str) -> super::NomResult<&str, i64> {
+    fn seconde(input: &str) -> super::NomResult<&str, u32> {
         context(
             "seconde",
             map_res(terminated(super::digits, tag("S")), str::parse),
@@ -135,17 +132,16 @@ pub(crate) fn duration(input: &str) -> super::NomResult<&str, chrono::Duration>
                 preceded(tag("P"), (opt(week), opt(day), opt(time))),
             ),
             |(neg, (w, d, t))| {
-                let mut duration = chrono::Duration::weeks(w.unwrap_or_default())
-                    + chrono::Duration::days(d.unwrap_or_default());
-
-                if let Some((h, i, s)) = t {
-                    duration = duration
-                        + chrono::Duration::hours(h)
-                        + chrono::Duration::minutes(i)
-                        + chrono::Duration::seconds(s);
+                let (hours, minutes, seconds) = t.unwrap_or_default();
+
+                crate::Duration {
+                    negative: neg.is_some(),
+                    weeks: w.unwrap_or_default(),
+                    days: d.unwrap_or_default(),
+                    hours,
+                    minutes,
+                    seconds,
                 }
-
-                if neg.is_some() { -duration } else { duration }
             },
         ),
     )
@@ -179,7 +175,71 @@ pub(crate) fn period(input: &str) -> crate::Result<crate::Period> {
  * See [3.3.11. Text](https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.11)
  */
 pub(crate) fn text(input: crate::ContentLine) -> crate::Result<crate::Text> {
-    Ok(input.into())
+    Ok(crate::Text {
+        text: unescape(&input.value),
+        params: input.params,
+    })
+}
+
+/**
+ * Splits a list of TEXT values on unescaped commas, each value is unescaped.
+ */
+pub(crate) fn texts(input: crate::ContentLine) -> crate::Result<Vec<crate::Text>> {
+    let mut values = Vec::new();
+    let mut start = 0;
+    let mut chars = input.value.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            ',' => {
+                values.push(&input.value[start..i]);
+                start = i + 1;
+            }
+            _ => (),
+        }
+    }
+    values.push(&input.value[start..]);
+
+    let texts = values
+        .into_iter()
+        .map(|x| crate::Text {
+            params: input.params.clone(),
+            text: unescape(x),
+        })
+        .collect();
+
+    Ok(texts)
+}
+
+/**
+ * Reverts the escaping of TEXT values, unknown escape sequences are kept
+ * verbatim.
+ */
+pub(crate) fn unescape(input: &str) -> String {
+    let mut s = String::with_capacity(input.len());
+    let mut chars = input.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            s.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n' | 'N') => s.push('\n'),
+            Some(c @ ('\\' | ';' | ',')) => s.push(c),
+            Some(c) => {
+                s.push('\\');
+                s.push(c);
+            }
+            None => s.push('\\'),
+        }
+    }
+
+    s
 }
 
 /**
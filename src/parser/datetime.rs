@@ -17,7 +17,7 @@ pub(crate) fn completed(input: crate::ContentLine) -> crate::Result<crate::DateT
 pub(crate) fn dtend(input: crate::ContentLine) -> crate::Result<crate::Date> {
     super::datatype::date_or_dt(&input.value)
         .map_err(crate::Error::from)
-        .map(|(_, x)| x)
+        .map(|(_, x)| x.with_tzid(input.params.get("TZID")))
 }
 
 /**
@@ -26,7 +26,7 @@ pub(crate) fn dtend(input: crate::ContentLine) -> crate::Result<crate::Date> {
 pub(crate) fn due(input: crate::ContentLine) -> crate::Result<crate::Date> {
     super::datatype::date_or_dt(&input.value)
         .map_err(crate::Error::from)
-        .map(|(_, x)| x)
+        .map(|(_, x)| x.with_tzid(input.params.get("TZID")))
 }
 
 /**
@@ -35,27 +35,21 @@ pub(crate) fn due(input: crate::ContentLine) -> crate::Result<crate::Date> {
 pub(crate) fn dtstart(input: crate::ContentLine) -> crate::Result<crate::Date> {
     super::datatype::date_or_dt(&input.value)
         .map_err(crate::Error::from)
-        .map(|(_, x)| x)
+        .map(|(_, x)| x.with_tzid(input.params.get("TZID")))
 }
 
 /**
  * See [3.8.2.5. Duration](https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.2.5)
  */
-pub(crate) fn duration(input: crate::ContentLine) -> crate::Result<chrono::Duration> {
-    super::datatype::duration(&input.value)
-        .map_err(crate::Error::from)
-        .map(|(_, x)| x)
+pub(crate) fn duration(input: crate::ContentLine) -> crate::Result<crate::Duration> {
+    input.value.parse()
 }
 
 /**
  * See [3.8.2.6. Free/Busy Time](https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.2.6)
  */
-pub(crate) fn freebusy(input: crate::ContentLine) -> crate::Result<Vec<crate::Period>> {
-    input
-        .value
-        .split(',')
-        .map(super::datatype::period)
-        .collect()
+pub(crate) fn freebusy(input: crate::ContentLine) -> crate::Result<crate::FreeBusy> {
+    input.try_into()
 }
 
 /**
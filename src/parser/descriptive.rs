@@ -5,15 +5,15 @@
 /**
  * See [3.8.1.1. Attachment](https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.1.1)
  */
-pub(crate) fn attach(input: crate::ContentLine) -> crate::Result<crate::Text> {
-    Ok(input.into())
+pub(crate) fn attach(input: crate::ContentLine) -> crate::Result<crate::Attachment> {
+    input.try_into()
 }
 
 /**
  * See [3.8.1.2. Categories](https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.1.2)
  */
 pub(crate) fn categories(input: crate::ContentLine) -> crate::Result<Vec<crate::Text>> {
-    Ok(input.value.split(',').map(crate::Text::from).collect())
+    super::datatype::texts(input)
 }
 
 /**
@@ -27,7 +27,7 @@ pub(crate) fn class(input: crate::ContentLine) -> crate::Result<crate::Class> {
  * See [3.8.1.4. Comment](https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.1.4)
  */
 pub(crate) fn comment(input: crate::ContentLine) -> crate::Result<crate::Text> {
-    Ok(input.into())
+    super::datatype::text(input)
 }
 
 /**
@@ -59,7 +59,7 @@ pub(crate) fn geo(input: crate::ContentLine) -> crate::Result<crate::Geo> {
  * See [3.8.1.7. Location](https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.1.7)
  */
 pub(crate) fn location(input: crate::ContentLine) -> crate::Result<crate::Text> {
-    Ok(input.into())
+    super::datatype::text(input)
 }
 
 /**
@@ -86,7 +86,7 @@ pub(crate) fn priority(input: crate::ContentLine) -> crate::Result<u8> {
  * See [3.8.1.10. Resources](https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.1.10)
  */
 pub(crate) fn resources(input: crate::ContentLine) -> crate::Result<Vec<crate::Text>> {
-    Ok(input.value.split(',').map(crate::Text::from).collect())
+    super::datatype::texts(input)
 }
 
 /**
@@ -100,5 +100,5 @@ pub(crate) fn status(input: crate::ContentLine) -> crate::Result<crate::Status>
  * See [3.8.1.12. Summary](https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.1.12)
  */
 pub(crate) fn summary(input: crate::ContentLine) -> crate::Result<crate::Text> {
-    Ok(input.into())
+    super::datatype::text(input)
 }
@@ -0,0 +1,558 @@
+/*!
+ * Line based parsing of a calendar, skipping the invalid properties and
+ * components instead of failing.
+ *
+ * It also locates the errors of the strict parser.
+ */
+
+/**
+ * A property with the position of its value.
+ */
+#[derive(Clone)]
+struct Property {
+    line: usize,
+    column: usize,
+    content_line: crate::ContentLine,
+}
+
+/**
+ * A component before its conversion.
+ */
+#[derive(Clone)]
+struct Node {
+    name: String,
+    path: String,
+    line: usize,
+    properties: Vec<Property>,
+    children: Vec<Node>,
+}
+
+impl Node {
+    fn new(name: &str, path: String, line: usize) -> Self {
+        Self {
+            name: name.to_string(),
+            path,
+            line,
+            properties: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    fn content_lines(&self) -> Vec<crate::ContentLine> {
+        self.properties
+            .iter()
+            .map(|x| x.content_line.clone())
+            .collect()
+    }
+
+    /**
+     * All the lines of the component, its children included.
+     */
+    fn raw(&self) -> Vec<crate::ContentLine> {
+        let mut content_lines = vec![content_line("BEGIN", &self.name)];
+        content_lines.extend(self.content_lines());
+
+        for child in &self.children {
+            content_lines.extend(child.raw());
+        }
+
+        content_lines.push(content_line("END", &self.name));
+
+        content_lines
+    }
+
+    fn error(&self, line: usize, expected: String) -> crate::ParseError {
+        crate::ParseError {
+            line,
+            column: 1,
+            path: self.path.clone(),
+            property: None,
+            expected,
+        }
+    }
+}
+
+impl From<&Node> for crate::RawComponent {
+    fn from(node: &Node) -> Self {
+        Self {
+            name: node.name.clone(),
+            properties: node.content_lines(),
+            components: node.children.iter().map(Self::from).collect(),
+        }
+    }
+}
+
+fn content_line(key: &str, value: &str) -> crate::ContentLine {
+    crate::ContentLine {
+        key: key.to_string(),
+        params: Default::default(),
+        value: value.to_string(),
+    }
+}
+
+/**
+ * Unfolded lines of `input` with the number of their first physical line.
+ */
+fn lines(input: &str) -> Vec<(usize, String)> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut lines = Vec::<(usize, String)>::new();
+
+    for (n, line) in input.split('\n').enumerate() {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some((_, last))) => last.push_str(continuation),
+            _ => lines.push((n + 1, line.to_string())),
+        }
+    }
+
+    lines
+}
+
+#[derive(Default)]
+struct Lenient {
+    diagnostics: Vec<crate::Diagnostic>,
+}
+
+impl Lenient {
+    fn diagnostic(&mut self, error: crate::ParseError, content_lines: Vec<crate::ContentLine>) {
+        self.diagnostics.push(crate::Diagnostic {
+            error,
+            content_lines,
+        });
+    }
+
+    /**
+     * Splits `input` in a tree of components.
+     */
+    fn tree(&mut self, input: &str) -> Vec<Node> {
+        let mut roots = Vec::new();
+        let mut stack = Vec::<Node>::new();
+        let mut last = 0;
+
+        for (n, line) in lines(input) {
+            if line.is_empty() {
+                continue;
+            }
+
+            last = n;
+
+            let error = |stack: &[Node], expected: String| crate::ParseError {
+                line: n,
+                column: 1,
+                path: stack.last().map(|x| x.path.clone()).unwrap_or_default(),
+                property: None,
+                expected,
+            };
+
+            if let Some(name) = line.strip_prefix("BEGIN:") {
+                let path = match stack.last() {
+                    Some(parent) => {
+                        let index = parent.children.iter().filter(|x| x.name == name).count();
+                        format!("{} > {name}[{index}]", parent.path)
+                    }
+                    None => name.to_string(),
+                };
+
+                stack.push(Node::new(name, path, n));
+            } else if let Some(name) = line.strip_prefix("END:") {
+                let Some(position) = stack.iter().rposition(|x| x.name == name) else {
+                    let error = error(&stack, format!("Unexpected END:{name}"));
+                    self.diagnostic(error, vec![line.into()]);
+                    continue;
+                };
+
+                while stack.len() > position {
+                    let node = stack.pop().unwrap();
+
+                    if node.name != name {
+                        let error = node.error(n, format!("Missing END:{}", node.name));
+                        self.diagnostic(error, Vec::new());
+                    }
+
+                    match stack.last_mut() {
+                        Some(parent) => parent.children.push(node),
+                        None => roots.push(node),
+                    }
+                }
+            } else {
+                match super::content_line(&format!("{line}\r\n")) {
+                    Ok((_, content_line)) => {
+                        let property = Property {
+                            line: n,
+                            column: line.chars().count() - content_line.value.chars().count() + 1,
+                            content_line,
+                        };
+
+                        match stack.last_mut() {
+                            Some(node) => node.properties.push(property),
+                            None => {
+                                let error =
+                                    error(&stack, "Property outside of a component".to_string());
+                                self.diagnostic(error, vec![property.content_line]);
+                            }
+                        }
+                    }
+                    Err(_) => {
+                        let error = error(
+                            &stack,
+                            "Expected a content line NAME;PARAM=VALUE:VALUE".to_string(),
+                        );
+                        self.diagnostic(error, vec![line.into()]);
+                    }
+                }
+            }
+        }
+
+        while let Some(node) = stack.pop() {
+            let error = node.error(last, format!("Missing END:{}", node.name));
+            self.diagnostic(error, Vec::new());
+
+            match stack.last_mut() {
+                Some(parent) => parent.children.push(node),
+                None => roots.push(node),
+            }
+        }
+
+        roots
+    }
+
+    /**
+     * Builds a component with `f`, the invalid optional properties are
+     * skipped and the whole component is dropped if `f` fails.
+     */
+    fn component<T>(
+        &mut self,
+        node: &Node,
+        f: impl FnOnce(
+            Vec<crate::ContentLine>,
+            &mut dyn FnMut(crate::ContentLine, crate::Error) -> crate::Result,
+        ) -> crate::Result<T>,
+    ) -> Option<T> {
+        let mut skipped = Vec::new();
+        let result = f(node.content_lines(), &mut |content_line, err| {
+            skipped.push((content_line, err));
+            Ok(())
+        });
+
+        for (content_line, err) in skipped {
+            let (line, column) = node
+                .properties
+                .iter()
+                .find(|x| x.content_line == content_line)
+                .map_or((node.line, 1), |x| (x.line, x.column));
+
+            let error = crate::ParseError {
+                line,
+                column,
+                path: format!("{} > {}", node.path, content_line.key),
+                property: Some(content_line.key.clone()),
+                expected: format!("Invalid value {:?}: {err}", content_line.value),
+            };
+
+            self.diagnostic(error, vec![content_line]);
+        }
+
+        match result {
+            Ok(component) => Some(component),
+            Err(err) => {
+                let mut error = node.error(node.line, err.to_string());
+
+                if let crate::Error::Invalid(key) | crate::Error::Missing(key) = err {
+                    if let Some(property) =
+                        node.properties.iter().rfind(|x| x.content_line.key == key)
+                    {
+                        error.line = property.line;
+                        error.column = property.column;
+                    }
+
+                    error.path = format!("{} > {key}", node.path);
+                    error.property = Some(key);
+                }
+
+                self.diagnostic(error, node.raw());
+                None
+            }
+        }
+    }
+
+    /**
+     * Builds a component without known sub-components, the unknown ones
+     * are added with `push_raw`.
+     */
+    fn leaf<T>(
+        &mut self,
+        node: &Node,
+        f: impl FnOnce(
+            Vec<crate::ContentLine>,
+            &mut dyn FnMut(crate::ContentLine, crate::Error) -> crate::Result,
+        ) -> crate::Result<T>,
+        push_raw: fn(&mut T, crate::RawComponent),
+    ) -> Option<T> {
+        let mut component = self.component(node, f)?;
+
+        for child in &node.children {
+            if let Some(raw) = self.raw(child) {
+                push_raw(&mut component, raw);
+            }
+        }
+
+        Some(component)
+    }
+
+    /**
+     * Keeps a component unknown to this crate, a known one is unexpected at
+     * this place.
+     */
+    fn raw(&mut self, node: &Node) -> Option<crate::RawComponent> {
+        if super::COMPONENTS.contains(&node.name.as_str()) {
+            self.unexpected(node);
+            None
+        } else {
+            Some(node.into())
+        }
+    }
+
+    fn unexpected(&mut self, node: &Node) {
+        let error = node.error(node.line, format!("Unexpected component {}", node.name));
+        self.diagnostic(error, node.raw());
+    }
+
+    fn vcalendar(&mut self, node: &Node) -> crate::VCalendar {
+        let mut properties = node.properties.clone();
+
+        for key in ["PRODID", "VERSION"] {
+            if !properties.iter().any(|x| x.content_line.key == key) {
+                let error = crate::ParseError {
+                    line: node.line,
+                    column: 1,
+                    path: format!("{} > {key}", node.path),
+                    property: Some(key.to_string()),
+                    expected: crate::Error::Missing(key.to_string()).to_string(),
+                };
+                self.diagnostic(error, Vec::new());
+
+                properties.push(Property {
+                    line: node.line,
+                    column: 1,
+                    content_line: content_line(key, ""),
+                });
+            }
+        }
+
+        let root = Node {
+            properties,
+            ..Node::new(&node.name, node.path.clone(), node.line)
+        };
+
+        let mut vcalendar = self
+            .component(&root, crate::VCalendar::from_content_lines)
+            .unwrap_or_default();
+
+        for child in &node.children {
+            match self.child(child) {
+                Some(crate::Component::Alarm(alarm)) => vcalendar.alarms.push(alarm),
+                Some(crate::Component::Event(event)) => vcalendar.events.push(event),
+                Some(crate::Component::Freebusy(freebusy)) => vcalendar.freebusy.push(freebusy),
+                Some(crate::Component::Journal(journal)) => vcalendar.journals.push(journal),
+                Some(crate::Component::Raw(raw)) => vcalendar.push_raw(raw),
+                Some(crate::Component::Todo(todo)) => vcalendar.todo.push(todo),
+                Some(crate::Component::Timezone(timezone)) => vcalendar.timezones.push(timezone),
+                None => (),
+            }
+        }
+
+        vcalendar
+    }
+
+    /**
+     * Builds a sub-component of a calendar.
+     */
+    fn child(&mut self, node: &Node) -> Option<crate::Component> {
+        let component = match node.name.as_str() {
+            "VALARM" => crate::Component::Alarm(self.leaf(
+                node,
+                crate::VAlarm::from_content_lines,
+                crate::VAlarm::push_raw,
+            )?),
+            "VEVENT" => crate::Component::Event(self.vevent(node)?),
+            "VFREEBUSY" => crate::Component::Freebusy(self.leaf(
+                node,
+                crate::VFreebusy::from_content_lines,
+                crate::VFreebusy::push_raw,
+            )?),
+            "VJOURNAL" => crate::Component::Journal(self.leaf(
+                node,
+                crate::VJournal::from_content_lines,
+                crate::VJournal::push_raw,
+            )?),
+            "VTIMEZONE" => crate::Component::Timezone(self.vtimezone(node)?),
+            "VTODO" => crate::Component::Todo(self.leaf(
+                node,
+                crate::VTodo::from_content_lines,
+                crate::VTodo::push_raw,
+            )?),
+            _ => crate::Component::Raw(self.raw(node)?),
+        };
+
+        Some(component)
+    }
+
+    fn vevent(&mut self, node: &Node) -> Option<crate::VEvent> {
+        let mut vevent = self.component(node, crate::VEvent::from_content_lines)?;
+
+        for child in &node.children {
+            if child.name == "VALARM" {
+                if let Some(valarm) = self.leaf(
+                    child,
+                    crate::VAlarm::from_content_lines,
+                    crate::VAlarm::push_raw,
+                ) {
+                    vevent.alarms.push(valarm);
+                }
+            } else if let Some(raw) = self.raw(child) {
+                vevent.push_raw(raw);
+            }
+        }
+
+        Some(vevent)
+    }
+
+    fn vtimezone(&mut self, node: &Node) -> Option<crate::VTimezone> {
+        let mut vtimezone = self.component(node, crate::VTimezone::from_content_lines)?;
+
+        for child in &node.children {
+            match child.name.as_str() {
+                "STANDARD" => {
+                    if let Some(standard) = self.leaf(
+                        child,
+                        crate::vtimezone::Standard::from_content_lines,
+                        crate::vtimezone::Standard::push_raw,
+                    ) {
+                        vtimezone.standard.push(standard);
+                    }
+                }
+                "DAYLIGHT" => {
+                    if let Some(daylight) = self.leaf(
+                        child,
+                        crate::vtimezone::Daylight::from_content_lines,
+                        crate::vtimezone::Daylight::push_raw,
+                    ) {
+                        vtimezone.daylight.push(daylight);
+                    }
+                }
+                _ => {
+                    if let Some(raw) = self.raw(child) {
+                        vtimezone.push_raw(raw);
+                    }
+                }
+            }
+        }
+
+        Some(vtimezone)
+    }
+}
+
+/**
+ * See [`crate::VCalendar::parse_lenient`].
+ */
+pub(crate) fn vcalendar(input: &str) -> (crate::VCalendar, Vec<crate::Diagnostic>) {
+    let mut lenient = Lenient::default();
+    let mut vcalendar = None;
+
+    for node in lenient.tree(input) {
+        if node.name == "VCALENDAR" && vcalendar.is_none() {
+            vcalendar = Some(lenient.vcalendar(&node));
+        } else {
+            lenient.unexpected(&node);
+        }
+    }
+
+    let vcalendar = vcalendar.unwrap_or_else(|| {
+        let error = crate::ParseError {
+            line: 1,
+            column: 1,
+            path: String::new(),
+            property: None,
+            expected: "Missing VCALENDAR".to_string(),
+        };
+        lenient.diagnostic(error, Vec::new());
+
+        crate::VCalendar::default()
+    });
+
+    (vcalendar, lenient.diagnostics)
+}
+
+/**
+ * Locates the error of the strict parser on `input`.
+ *
+ * The input is parsed again line by line and the first error is reported, otherwise the position of `err` in the unfolded input is used.
+ */
+pub(crate) fn error(
+    input: &str,
+    err: nom::Err<nom_language::error::VerboseError<&str>>,
+) -> crate::Error {
+    let mut lenient = Lenient::default();
+    let roots = lenient.tree(input);
+    let syntax = lenient.diagnostics.len();
+
+    if let Some(root) = roots.first() {
+        match root.name.as_str() {
+            "VCALENDAR" => {
+                lenient.vcalendar(root);
+            }
+            "STANDARD" => {
+                lenient.leaf(
+                    root,
+                    crate::vtimezone::Standard::from_content_lines,
+                    crate::vtimezone::Standard::push_raw,
+                );
+            }
+            "DAYLIGHT" => {
+                lenient.leaf(
+                    root,
+                    crate::vtimezone::Daylight::from_content_lines,
+                    crate::vtimezone::Daylight::push_raw,
+                );
+            }
+            _ => {
+                lenient.child(root);
+            }
+        }
+    }
+
+    // syntax errors first, then the first invalid value
+    let (syntax, values) = lenient.diagnostics.split_at(syntax);
+    let first = |diagnostics: &[crate::Diagnostic]| {
+        diagnostics
+            .iter()
+            .min_by_key(|x| (x.error.line, x.error.column))
+            .map(|x| x.error.clone())
+    };
+
+    if let Some(error) = first(syntax).or_else(|| first(values)) {
+        return crate::Error::Parse(error);
+    }
+
+    let remaining = match &err {
+        nom::Err::Error(e) | nom::Err::Failure(e) => e.errors.first().map_or("", |(x, _)| *x),
+        nom::Err::Incomplete(_) => "",
+    };
+    let unfolded = super::unfold(input);
+    let before = &unfolded[..unfolded.len().saturating_sub(remaining.len())];
+
+    crate::Error::Parse(crate::ParseError {
+        line: before.matches('\n').count() + 1,
+        column: before
+            .rsplit('\n')
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .count()
+            + 1,
+        path: String::new(),
+        property: None,
+        expected: crate::Error::from(err).to_string(),
+    })
+}
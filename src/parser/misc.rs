@@ -7,15 +7,25 @@
  */
 pub(crate) fn rstatus(input: crate::ContentLine) -> crate::Result<crate::RequestStatus> {
     use nom::Parser as _;
-    use nom::bytes::complete::{take_till, take_while};
-    use nom::character::complete::char;
+    use nom::branch::alt;
+    use nom::bytes::complete::take_while;
+    use nom::character::complete::{anychar, char, none_of};
+    use nom::combinator::recognize;
     use nom::combinator::{map, opt};
     use nom::error::context;
+    use nom::multi::many0_count;
     use nom::number::complete::float;
     use nom::sequence::preceded;
 
     fn text(input: &str) -> super::NomResult<&str, &str> {
-        context("text", take_till(|c| c == ';')).parse(input)
+        context(
+            "text",
+            recognize(many0_count(alt((
+                recognize(preceded(char('\\'), anychar)),
+                recognize(none_of("\\;")),
+            )))),
+        )
+        .parse(input)
     }
 
     fn end(input: &str) -> super::NomResult<&str, &str> {
@@ -28,8 +38,8 @@ pub(crate) fn rstatus(input: crate::ContentLine) -> crate::Result<crate::Request
             (
                 float,
                 char(';'),
-                map(text, String::from),
-                opt(preceded(char(';'), map(end, String::from))),
+                map(text, super::datatype::unescape),
+                opt(preceded(char(';'), map(end, super::datatype::unescape))),
             ),
             |(statcode, _, statdesc, extdata)| crate::RequestStatus {
                 statcode,
@@ -69,8 +79,8 @@ mod test {
             crate::parser::rstatus("2.8; Success\\, repeating event ignored. Scheduled\r\n as a single event.;RRULE:FREQ=WEEKLY\\;INTERVAL=2".into()).unwrap(),
             crate::RequestStatus {
                 statcode: 2.8,
-                statdesc: " Success\\, repeating event ignored. Scheduled\r\n as a single event.".to_string(),
-                extdata: Some("RRULE:FREQ=WEEKLY\\;INTERVAL=2".to_string()),
+                statdesc: " Success, repeating event ignored. Scheduled\r\n as a single event.".to_string(),
+                extdata: Some("RRULE:FREQ=WEEKLY;INTERVAL=2".to_string()),
             }
         );
 
@@ -1,4 +1,5 @@
 pub(crate) mod datatype;
+pub(crate) mod lenient;
 
 mod alarm;
 mod change;
@@ -29,7 +30,6 @@ use nom::combinator::{map, map_res, not, opt};
 use nom::error::context;
 use nom::multi::{count, fold_many0};
 use nom::sequence::{preceded, separated_pair};
-use std::collections::BTreeMap;
 
 pub(crate) type NomResult<I, O> = nom::IResult<I, O, nom_language::error::VerboseError<I>>;
 
@@ -61,55 +61,104 @@ fn key(input: &str) -> NomResult<&str, &str> {
     context("key", take_while(is_alphanumeric)).parse(input)
 }
 
-fn attr(input: &str) -> NomResult<&str, &str> {
-    context(
-        "attr",
-        preceded(opt(tag("\r\n ")), take_till(|c| c == ';' || c == ':')),
-    )
-    .parse(input)
-}
-
 fn value(input: &str) -> NomResult<&str, &str> {
     context("value", take_till(is_line_ending)).parse(input)
 }
 
-fn quote(chr: char) -> bool {
-    chr == '"'
-}
+/**
+ * A quoted or unquoted parameter value, caret-decoded.
+ *
+ * See [3.2. Property Parameters](https://datatracker.ietf.org/doc/html/rfc5545#section-3.2)
+ * and [RFC 6868](https://datatracker.ietf.org/doc/html/rfc6868)
+ */
+fn param_value(input: &str) -> NomResult<&str, String> {
+    use nom::branch::alt;
+    use nom::sequence::delimited;
 
-fn quoted_param(input: &str) -> NomResult<&str, (&str, &str)> {
     context(
-        "quoted_param",
-        separated_pair(key, tag("=\""), take_till(quote)),
+        "param_value",
+        map(
+            alt((
+                delimited(char('"'), take_till(|c| c == '"'), char('"')),
+                take_till(|c| matches!(c, ';' | ':' | ',' | '"') || is_line_ending(c)),
+            )),
+            caret_decode,
+        ),
     )
     .parse(input)
 }
 
-fn param(input: &str) -> NomResult<&str, (&str, &str)> {
-    use nom::branch::alt;
-    use nom::sequence::delimited;
+/**
+ * See [RFC 6868](https://datatracker.ietf.org/doc/html/rfc6868), unknown
+ * sequences are kept verbatim.
+ */
+fn caret_decode(s: &str) -> String {
+    let mut decoded = String::with_capacity(s.len());
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '^' {
+            decoded.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('^') => decoded.push('^'),
+            Some('n' | 'N') => decoded.push('\n'),
+            Some('\'') => decoded.push('"'),
+            Some(c) => {
+                decoded.push('^');
+                decoded.push(c);
+            }
+            None => decoded.push('^'),
+        }
+    }
+
+    decoded
+}
+
+fn param(input: &str) -> NomResult<&str, (&str, Vec<String>)> {
+    use nom::multi::separated_list1;
 
     context(
         "param",
-        alt((
-            delimited(char(';'), quoted_param, char('"')),
-            preceded(char(';'), separated_pair(key, char('='), attr)),
-        )),
+        preceded(
+            char(';'),
+            separated_pair(key, char('='), separated_list1(char(','), param_value)),
+        ),
     )
     .parse(input)
 }
 
-fn params(input: &str) -> NomResult<&str, BTreeMap<String, String>> {
+fn params(input: &str) -> NomResult<&str, crate::Params> {
     context(
         "params",
-        fold_many0(param, BTreeMap::new, |mut acc, (key, value)| {
-            acc.insert(key.to_string(), value.to_string());
+        fold_many0(param, crate::Params::new, |mut acc, (key, values)| {
+            for value in values {
+                acc.append(key.to_string(), value);
+            }
             acc
         }),
     )
     .parse(input)
 }
 
+/**
+ * Removes the byte order mark and joins folded lines, whatever their line
+ * ending.
+ *
+ * See [3.1. Content Lines](https://datatracker.ietf.org/doc/html/rfc5545#section-3.1)
+ */
+pub(crate) fn unfold(input: &str) -> String {
+    input
+        .strip_prefix('\u{feff}')
+        .unwrap_or(input)
+        .replace("\r\n ", "")
+        .replace("\r\n\t", "")
+        .replace("\n ", "")
+        .replace("\n\t", "")
+}
+
 /**
  * See [3.1. Content Lines](https://datatracker.ietf.org/doc/html/rfc5545#section-3.1)
  */
@@ -6,12 +6,14 @@
  * See [3.8.5.1. Exception Date-Times](https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.5.1)
  */
 pub(crate) fn exdate(input: crate::ContentLine) -> crate::Result<Vec<crate::Date>> {
+    let tzid = input.params.get("TZID");
+
     input
         .value
         .split(',')
         .map(|x| {
             super::datatype::date_or_dt(x)
-                .map(|x| x.1)
+                .map(|x| x.1.with_tzid(tzid))
                 .map_err(crate::Error::from)
         })
         .collect()
@@ -22,10 +24,11 @@ pub(crate) fn exdate(input: crate::ContentLine) -> crate::Result<Vec<crate::Date
  */
 pub(crate) fn rdate(input: crate::ContentLine) -> crate::Result<crate::RDate> {
     let tokens = input.value.split(',');
+    let tzid = input.params.get("TZID");
 
     if input.params.get("VALUE") == Some(&"PERIOD".to_string()) {
         let periods = tokens
-            .map(super::datatype::period)
+            .map(|x| super::datatype::period(x).map(|x| x.with_tzid(tzid)))
             .collect::<crate::Result<Vec<_>>>()?;
 
         Ok(crate::RDate::Period(periods))
@@ -33,7 +36,7 @@ pub(crate) fn rdate(input: crate::ContentLine) -> crate::Result<crate::RDate> {
         let dates = tokens
             .map(|x| {
                 super::datatype::date_or_dt(x)
-                    .map(|x| x.1)
+                    .map(|x| x.1.with_tzid(tzid))
                     .map_err(crate::Error::from)
             })
             .collect::<crate::Result<Vec<_>>>()?;
@@ -65,7 +68,9 @@ pub(crate) fn rrule(input: crate::ContentLine) -> crate::Result<crate::Recur> {
         .parse(input)
     }
 
-    fn by(input: &&&str) -> crate::Result<Vec<i8>> {
+    fn by<T: std::str::FromStr<Err = std::num::ParseIntError>>(
+        input: &&&str,
+    ) -> crate::Result<Vec<T>> {
         input
             .split(',')
             .map(str::parse)
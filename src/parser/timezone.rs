@@ -6,14 +6,14 @@
  * See [3.8.3.1. Time Zone Identifier](https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.3.1)
  */
 pub(crate) fn tzid(input: crate::ContentLine) -> crate::Result<crate::Text> {
-    Ok(input.into())
+    super::datatype::text(input)
 }
 
 /**
  * See [3.8.3.2. Time Zone Name](https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.3.2)
  */
 pub(crate) fn tzname(input: crate::ContentLine) -> crate::Result<crate::Text> {
-    Ok(input.into())
+    super::datatype::text(input)
 }
 
 /**
@@ -0,0 +1,162 @@
+use base64::Engine as _;
+
+/**
+ * See [3.8.1.1. Attachment](https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.1.1)
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Attachment {
+    Uri(crate::Uri),
+    Binary(Binary),
+}
+
+impl Default for Attachment {
+    fn default() -> Self {
+        Self::Uri(crate::Uri::default())
+    }
+}
+
+impl Attachment {
+    /**
+     * The media type of the attachment.
+     */
+    #[must_use]
+    pub fn fmttype(&self) -> Option<&str> {
+        match self {
+            Self::Uri(uri) => uri.params.get("FMTTYPE").map(String::as_str),
+            Self::Binary(binary) => binary.fmttype.as_deref(),
+        }
+    }
+}
+
+impl TryFrom<crate::ContentLine> for Attachment {
+    type Error = crate::Error;
+
+    fn try_from(value: crate::ContentLine) -> Result<Self, Self::Error> {
+        let mut params = value.params;
+
+        let binary = params
+            .get("VALUE")
+            .is_some_and(|x| x.eq_ignore_ascii_case("BINARY"))
+            || params
+                .get("ENCODING")
+                .is_some_and(|x| x.eq_ignore_ascii_case("BASE64"));
+
+        if !binary {
+            return Ok(Self::Uri(crate::Uri {
+                params,
+                uri: value.value,
+            }));
+        }
+
+        let data = base64::engine::general_purpose::STANDARD
+            .decode(value.value.trim())
+            .map_err(|err| crate::Error::Base64(err.to_string()))?;
+
+        params.remove("VALUE");
+        params.remove("ENCODING");
+        let fmttype = params.remove("FMTTYPE").and_then(|x| x.into_iter().next());
+
+        Ok(Self::Binary(Binary {
+            data,
+            fmttype,
+            params,
+        }))
+    }
+}
+
+impl From<crate::Uri> for Attachment {
+    fn from(value: crate::Uri) -> Self {
+        Self::Uri(value)
+    }
+}
+
+impl From<Binary> for Attachment {
+    fn from(value: Binary) -> Self {
+        Self::Binary(value)
+    }
+}
+
+impl From<String> for Attachment {
+    fn from(value: String) -> Self {
+        Self::Uri(value.into())
+    }
+}
+
+impl From<&str> for Attachment {
+    fn from(value: &str) -> Self {
+        Self::Uri(value.into())
+    }
+}
+
+impl crate::ser::Serialize for Attachment {
+    fn ical(&self) -> crate::Result<String> {
+        match self {
+            Self::Uri(uri) => Ok(uri.uri.clone()),
+            Self::Binary(binary) => binary.ical(),
+        }
+    }
+
+    fn attr(&self) -> Option<String> {
+        match self {
+            Self::Uri(uri) => uri.attr(),
+            Self::Binary(binary) => binary.attr(),
+        }
+    }
+}
+
+/**
+ * An inline attachment, base64 encoded in the calendar.
+ *
+ * See [3.3.1. Binary](https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.1)
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Binary {
+    #[cfg_attr(feature = "serde", serde(with = "crate::ser::serde::base64"))]
+    pub data: Vec<u8>,
+    pub fmttype: Option<String>,
+    pub params: crate::Params,
+}
+
+impl crate::ser::Serialize for Binary {
+    fn ical(&self) -> crate::Result<String> {
+        Ok(base64::engine::general_purpose::STANDARD.encode(&self.data))
+    }
+
+    fn attr(&self) -> Option<String> {
+        let mut params = self.params.clone();
+        params.insert("ENCODING".to_string(), "BASE64".to_string());
+        params.insert("VALUE".to_string(), "BINARY".to_string());
+
+        if let Some(fmttype) = &self.fmttype {
+            params.insert("FMTTYPE".to_string(), fmttype.clone());
+        }
+
+        params.ical().ok()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn ser() -> crate::Result {
+        let attach = crate::Attachment::from("ftp://example.com/pub/reports/r-960812.ps");
+        assert_eq!(
+            crate::ser::ical(&attach)?,
+            "ftp://example.com/pub/reports/r-960812.ps"
+        );
+
+        let attach = crate::Attachment::Binary(crate::Binary {
+            data: b"text".to_vec(),
+            fmttype: Some("text/plain".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(
+            crate::ser::ical(&attach)?,
+            "ENCODING=BASE64;FMTTYPE=text/plain;VALUE=BINARY:dGV4dA=="
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,260 @@
+/**
+ * A calendar user, the value of the `ATTENDEE` and `ORGANIZER` properties,
+ * with its parameters.
+ *
+ * The parameters without a dedicated field are kept in `params`.
+ *
+ * See [3.3.3. Calendar User Address](https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.3)
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CalAddress {
+    pub uri: String,
+    /** See [3.2.2. Common Name](https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.2) */
+    pub cn: Option<String>,
+    /** See [3.2.3. Calendar User Type](https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.3) */
+    pub cutype: Option<CuType>,
+    /** See [3.2.16. Participation Role](https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.16) */
+    pub role: Option<Role>,
+    /** See [3.2.12. Participation Status](https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.12) */
+    pub partstat: Option<PartStat>,
+    /** See [3.2.17. RSVP Expectation](https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.17) */
+    pub rsvp: Option<bool>,
+    /** See [3.2.5. Delegatees](https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.5) */
+    pub delegated_to: Vec<String>,
+    /** See [3.2.4. Delegators](https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.4) */
+    pub delegated_from: Vec<String>,
+    /** See [3.2.11. Group or List Membership](https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.11) */
+    pub member: Vec<String>,
+    /** See [3.2.18. Sent By](https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.18) */
+    pub sent_by: Option<String>,
+    /** See [3.2.6. Directory Entry Reference](https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.6) */
+    pub dir: Option<String>,
+    /** See [3.2.10. Language](https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.10) */
+    pub language: Option<String>,
+    pub params: crate::Params,
+}
+
+impl CalAddress {
+    /**
+     * The participation status, `NEEDS-ACTION` if not specified.
+     */
+    #[must_use]
+    pub fn partstat(&self) -> PartStat {
+        self.partstat.clone().unwrap_or(PartStat::NeedsAction)
+    }
+
+    /**
+     * The participation role, `REQ-PARTICIPANT` if not specified.
+     */
+    #[must_use]
+    pub fn role(&self) -> Role {
+        self.role.clone().unwrap_or(Role::ReqParticipant)
+    }
+
+    /**
+     * The calendar user type, `INDIVIDUAL` if not specified.
+     */
+    #[must_use]
+    pub fn cutype(&self) -> CuType {
+        self.cutype.clone().unwrap_or(CuType::Individual)
+    }
+}
+
+impl From<crate::ContentLine> for CalAddress {
+    fn from(value: crate::ContentLine) -> Self {
+        let mut params = value.params;
+
+        let rsvp = params.take_single("RSVP").and_then(|x| {
+            match x.to_ascii_uppercase().as_str() {
+                "TRUE" => Some(true),
+                "FALSE" => Some(false),
+                // an invalid value is kept as is
+                _ => {
+                    params.insert("RSVP".to_string(), x);
+                    None
+                }
+            }
+        });
+
+        Self {
+            uri: value.value,
+            cn: params.take_single("CN"),
+            cutype: params.take_single("CUTYPE").map(CuType::from),
+            role: params.take_single("ROLE").map(Role::from),
+            partstat: params.take_single("PARTSTAT").map(PartStat::from),
+            rsvp,
+            delegated_to: params.remove("DELEGATED-TO").unwrap_or_default(),
+            delegated_from: params.remove("DELEGATED-FROM").unwrap_or_default(),
+            member: params.remove("MEMBER").unwrap_or_default(),
+            sent_by: params.take_single("SENT-BY"),
+            dir: params.take_single("DIR"),
+            language: params.take_single("LANGUAGE"),
+            params,
+        }
+    }
+}
+
+impl std::fmt::Display for CalAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.uri)
+    }
+}
+
+impl std::ops::Deref for CalAddress {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.uri
+    }
+}
+
+impl From<String> for CalAddress {
+    fn from(value: String) -> Self {
+        Self {
+            uri: value,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&str> for CalAddress {
+    fn from(value: &str) -> Self {
+        value.to_string().into()
+    }
+}
+
+impl crate::ser::Serialize for CalAddress {
+    fn ical(&self) -> crate::Result<String> {
+        Ok(self.uri.clone())
+    }
+
+    fn attr(&self) -> Option<String> {
+        let mut params = self.params.clone();
+        let mut set = |name: &str, values: Vec<String>| {
+            for value in values {
+                params.append(name.to_string(), value);
+            }
+        };
+
+        set("CN", self.cn.iter().cloned().collect());
+        set(
+            "CUTYPE",
+            self.cutype.iter().map(ToString::to_string).collect(),
+        );
+        set("ROLE", self.role.iter().map(ToString::to_string).collect());
+        set(
+            "PARTSTAT",
+            self.partstat.iter().map(ToString::to_string).collect(),
+        );
+        set(
+            "RSVP",
+            self.rsvp
+                .iter()
+                .map(|x| x.to_string().to_uppercase())
+                .collect(),
+        );
+        set("DELEGATED-TO", self.delegated_to.clone());
+        set("DELEGATED-FROM", self.delegated_from.clone());
+        set("MEMBER", self.member.clone());
+        set("SENT-BY", self.sent_by.iter().cloned().collect());
+        set("DIR", self.dir.iter().cloned().collect());
+        set("LANGUAGE", self.language.iter().cloned().collect());
+
+        if params.is_empty() {
+            None
+        } else {
+            params.ical().ok()
+        }
+    }
+}
+
+super::param!(
+    /**
+     * See [3.2.3. Calendar User Type](https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.3)
+     */
+    CuType {
+        Individual => "INDIVIDUAL",
+        Group => "GROUP",
+        Resource => "RESOURCE",
+        Room => "ROOM",
+        Unknown => "UNKNOWN",
+    }
+);
+
+super::param!(
+    /**
+     * See [3.2.16. Participation Role](https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.16)
+     */
+    Role {
+        Chair => "CHAIR",
+        ReqParticipant => "REQ-PARTICIPANT",
+        OptParticipant => "OPT-PARTICIPANT",
+        NonParticipant => "NON-PARTICIPANT",
+    }
+);
+
+super::param!(
+    /**
+     * See [3.2.12. Participation Status](https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.12)
+     */
+    PartStat {
+        NeedsAction => "NEEDS-ACTION",
+        Accepted => "ACCEPTED",
+        Declined => "DECLINED",
+        Tentative => "TENTATIVE",
+        Delegated => "DELEGATED",
+        Completed => "COMPLETED",
+        InProcess => "IN-PROCESS",
+    }
+);
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn parse() -> crate::Result {
+        let (_, content_line) = crate::parser::content_line(
+            "ATTENDEE;CUTYPE=GROUP;ROLE=CHAIR;PARTSTAT=x-waiting;RSVP=TRUE;DELEGATED-TO=\"mailto:a@example.com\",\"mailto:b@example.com\";CN=\"Doe, John\";X-NUM-GUESTS=0:mailto:jdoe@example.com\r\n",
+        )?;
+        let attendee = crate::CalAddress::from(content_line);
+
+        assert_eq!(attendee.uri, "mailto:jdoe@example.com");
+        assert_eq!(attendee.cn.as_deref(), Some("Doe, John"));
+        assert_eq!(attendee.cutype(), crate::CuType::Group);
+        assert_eq!(attendee.role(), crate::Role::Chair);
+        assert_eq!(
+            attendee.partstat(),
+            crate::PartStat::Custom("x-waiting".to_string())
+        );
+        assert_eq!(attendee.rsvp, Some(true));
+        assert_eq!(
+            attendee.delegated_to,
+            ["mailto:a@example.com", "mailto:b@example.com"]
+        );
+        assert_eq!(
+            attendee.params.get("X-NUM-GUESTS").map(String::as_str),
+            Some("0")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn ser() -> crate::Result {
+        let attendee = crate::CalAddress {
+            uri: "mailto:jdoe@example.com".to_string(),
+            cn: Some("Doe, John".to_string()),
+            partstat: Some(crate::PartStat::Accepted),
+            rsvp: Some(false),
+            member: vec!["mailto:list@example.com".to_string()],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            crate::ser::ical(&attendee)?,
+            "CN=\"Doe, John\";MEMBER=\"mailto:list@example.com\";PARTSTAT=ACCEPTED;RSVP=FALSE:mailto:jdoe@example.com"
+        );
+
+        Ok(())
+    }
+}
@@ -1,16 +1,22 @@
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/**
+ * See [3.3.5. Date-Time](https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.5)
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DateTime {
+    /** Date with local time, not bound to any time zone */
     Naive(chrono::NaiveDateTime),
+    /** Date with local time of the machine time zone, serialized in UTC */
     Local(chrono::DateTime<chrono::Local>),
+    /** Date with UTC time */
+    Utc(chrono::DateTime<chrono::Utc>),
+    /** Date with local time and time zone reference */
+    Zoned(chrono::NaiveDateTime, String),
 }
 
 impl DateTime {
     #[must_use]
     pub fn date_naive(&self) -> chrono::NaiveDate {
-        match self {
-            Self::Naive(date) => date.date(),
-            Self::Local(date) => date.date_naive(),
-        }
+        self.naive().date()
     }
 
     #[must_use]
@@ -19,16 +25,113 @@ impl DateTime {
         fmt: &'a str,
     ) -> chrono::format::DelayedFormat<chrono::format::StrftimeItems<'a>> {
         match self {
-            Self::Naive(date) => date.format(fmt),
+            Self::Naive(date) | Self::Zoned(date, _) => date.format(fmt),
             Self::Local(date) => date.format(fmt),
+            Self::Utc(date) => date.format(fmt),
         }
     }
 
     #[must_use]
     pub fn naive(&self) -> chrono::NaiveDateTime {
         match self {
-            Self::Naive(date) => *date,
+            Self::Naive(date) | Self::Zoned(date, _) => *date,
             Self::Local(date) => date.naive_local(),
+            Self::Utc(date) => date.naive_utc(),
+        }
+    }
+
+    /**
+     * Returns the `TZID` of a zoned date-time.
+     */
+    #[must_use]
+    pub fn tzid(&self) -> Option<&str> {
+        match self {
+            Self::Zoned(_, tzid) => Some(tzid),
+            _ => None,
+        }
+    }
+
+    /**
+     * Returns a date-time of the same kind with `naive` as wall time.
+     *
+     * A local time falling in a DST gap is interpreted with the offset in
+     * effect before the gap.
+     */
+    pub(crate) fn with_naive(&self, naive: chrono::NaiveDateTime) -> Self {
+        use chrono::TimeZone as _;
+
+        match self {
+            Self::Naive(_) => Self::Naive(naive),
+            Self::Local(_) => {
+                let local = match naive.and_local_timezone(chrono::Local) {
+                    chrono::LocalResult::Single(local)
+                    | chrono::LocalResult::Ambiguous(local, _) => local,
+                    chrono::LocalResult::None => {
+                        let offset = chrono::Local
+                            .offset_from_utc_datetime(&(naive - chrono::TimeDelta::days(1)));
+
+                        (naive - offset).and_utc().with_timezone(&chrono::Local)
+                    }
+                };
+
+                Self::Local(local)
+            }
+            Self::Utc(_) => Self::Utc(naive.and_utc()),
+            Self::Zoned(_, tzid) => Self::Zoned(naive, tzid.clone()),
+        }
+    }
+
+    /**
+     * Same as adding `rhs`, but the exact part of a zoned date-time elapses
+     * in its `vtimezone`, across the DST changes.
+     *
+     * ```
+     * let vtimezone = ikal::VTimezone::from_zoneinfo("Europe/Paris", 2024..=2024)?;
+     * let dtstart: ikal::DateTime = "20240330T120000".parse()?;
+     * let dtstart = ikal::DateTime::Zoned(dtstart.naive(), "Europe/Paris".to_string());
+     *
+     * let end = dtstart.add_in("PT24H".parse()?, &vtimezone);
+     * assert_eq!(end.to_string(), "20240331T130000");
+     * # Ok::<(), ikal::Error>(())
+     * ```
+     */
+    #[must_use]
+    pub fn add_in(self, rhs: crate::Duration, vtimezone: &crate::VTimezone) -> Self {
+        let Self::Zoned(naive, tzid) = &self else {
+            return self + rhs;
+        };
+
+        let wall = *naive + chrono::TimeDelta::days(rhs.num_days());
+
+        let Some(utc) = vtimezone.to_utc(wall).map(|x| x.naive_utc() + rhs.exact()) else {
+            return self + rhs;
+        };
+
+        match vtimezone.offset_at_utc(utc) {
+            Some(offset) => Self::Zoned(utc + offset, tzid.clone()),
+            None => self + rhs,
+        }
+    }
+
+    /**
+     * Binds a floating date-time to the `tzid` time zone.
+     */
+    pub(crate) fn with_tzid(self, tzid: Option<&String>) -> Self {
+        match (self, tzid) {
+            (Self::Naive(naive), Some(tzid)) => Self::Zoned(naive, tzid.clone()),
+            (date_time, _) => date_time,
+        }
+    }
+
+    /**
+     * Sort key, see the [`Ord`] implementation.
+     */
+    fn key(&self) -> (chrono::NaiveDateTime, u8, Option<&str>) {
+        match self {
+            Self::Naive(naive) => (*naive, 1, None),
+            Self::Local(local) => (local.naive_utc(), 2, None),
+            Self::Utc(utc) => (utc.naive_utc(), 3, None),
+            Self::Zoned(naive, tzid) => (*naive, 4, Some(tzid)),
         }
     }
 }
@@ -59,25 +162,33 @@ impl From<chrono::DateTime<chrono::Local>> for DateTime {
     }
 }
 
+impl From<chrono::DateTime<chrono::Utc>> for DateTime {
+    fn from(value: chrono::DateTime<chrono::Utc>) -> Self {
+        Self::Utc(value)
+    }
+}
+
 impl From<DateTime> for chrono::NaiveDateTime {
     fn from(value: DateTime) -> Self {
-        match value {
-            DateTime::Naive(naive) => naive,
-            DateTime::Local(local) => local.naive_local(),
-        }
+        value.naive()
     }
 }
 
+/**
+ * Floating and zoned date-times are interpreted in the local time zone, see
+ * [`crate::VCalendar`] to resolve a `TZID`.
+ */
 impl TryFrom<DateTime> for chrono::DateTime<chrono::Local> {
     type Error = crate::Error;
 
     fn try_from(value: DateTime) -> Result<Self, Self::Error> {
         match value {
-            DateTime::Naive(naive) => naive
+            DateTime::Naive(naive) | DateTime::Zoned(naive, _) => naive
                 .and_local_timezone(chrono::Local)
                 .earliest()
                 .ok_or(crate::Error::Local(value)),
             DateTime::Local(local) => Ok(local),
+            DateTime::Utc(utc) => Ok(utc.with_timezone(&chrono::Local)),
         }
     }
 }
@@ -101,8 +212,11 @@ impl std::ops::Sub<chrono::Duration> for DateTime {
 impl std::fmt::Display for DateTime {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            DateTime::Naive(naive) => naive.format("%Y%m%dT%H%M%S").fmt(f),
-            DateTime::Local(local) => local.format("%Y%m%dT%H%M%SZ").fmt(f),
+            DateTime::Naive(naive) | DateTime::Zoned(naive, _) => {
+                naive.format("%Y%m%dT%H%M%S").fmt(f)
+            }
+            DateTime::Local(local) => local.to_utc().format("%Y%m%dT%H%M%SZ").fmt(f),
+            DateTime::Utc(utc) => utc.format("%Y%m%dT%H%M%SZ").fmt(f),
         }
     }
 }
@@ -116,9 +230,58 @@ impl std::str::FromStr for DateTime {
         } else {
             let naive = chrono::NaiveDateTime::parse_from_str(s, "%Y%m%dT%H%M%SZ")?;
 
-            Ok(Self::Local(
-                naive.and_local_timezone(chrono::Local).unwrap(),
-            ))
+            Ok(Self::Utc(naive.and_utc()))
+        }
+    }
+}
+
+/**
+ * ISO 8601 format, a zoned date-time is suffixed by its time zone between
+ * brackets: `2024-01-01T09:00:00[Europe/Paris]`.
+ */
+#[cfg(feature = "serde")]
+impl serde::Serialize for DateTime {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Naive(naive) => serializer.collect_str(&naive.format(ISO)),
+            Self::Local(local) => serializer.collect_str(&local.format("%Y-%m-%dT%H:%M:%S%:z")),
+            Self::Utc(utc) => serializer.collect_str(&utc.format("%Y-%m-%dT%H:%M:%SZ")),
+            Self::Zoned(naive, tzid) => {
+                serializer.collect_str(&format_args!("{}[{tzid}]", naive.format(ISO)))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for DateTime {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+
+        Self::from_iso(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+const ISO: &str = "%Y-%m-%dT%H:%M:%S";
+
+#[cfg(feature = "serde")]
+impl DateTime {
+    fn from_iso(s: &str) -> crate::Result<Self> {
+        if let Some((naive, tzid)) = s.strip_suffix(']').and_then(|x| x.split_once('[')) {
+            let naive = chrono::NaiveDateTime::parse_from_str(naive, ISO)?;
+
+            Ok(Self::Zoned(naive, tzid.to_string()))
+        } else if let Some(naive) = s.strip_suffix('Z') {
+            let naive = chrono::NaiveDateTime::parse_from_str(naive, ISO)?;
+
+            Ok(Self::Utc(naive.and_utc()))
+        } else if let Ok(naive) = chrono::NaiveDateTime::parse_from_str(s, ISO) {
+            Ok(Self::Naive(naive))
+        } else {
+            let local = chrono::DateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%:z")?;
+
+            Ok(Self::Local(local.with_timezone(&chrono::Local)))
         }
     }
 }
@@ -129,9 +292,16 @@ impl std::cmp::PartialOrd for DateTime {
     }
 }
 
+/**
+ * The UTC and local date-times are ordered by instant, the floating and
+ * zoned ones by wall time, see [`crate::VCalendar::to_utc`] to compare the
+ * instants of date-times of different kinds.
+ *
+ * Equal times are ordered by kind and `TZID`, to be consistent with `Eq`.
+ */
 impl std::cmp::Ord for DateTime {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.naive().cmp(&other.naive())
+        self.key().cmp(&other.key())
     }
 }
 
@@ -142,13 +312,43 @@ impl std::ops::Add<chrono::TimeDelta> for DateTime {
         match self {
             Self::Naive(naive) => Self::Naive(naive + rhs),
             Self::Local(local) => Self::Local(local + rhs),
+            Self::Utc(utc) => Self::Utc(utc + rhs),
+            Self::Zoned(naive, tzid) => Self::Zoned(naive + rhs, tzid),
         }
     }
 }
 
-crate::ser::ical_for_tostring!(DateTime);
+/**
+ * The days are added to the wall time and the exact part elapses, see
+ * [`crate::Duration`]. Floating and zoned date-times have no offset, the
+ * exact part is also added to their wall time, use [`DateTime::add_in`] to
+ * resolve it in the time zone.
+ */
+impl std::ops::Add<crate::Duration> for DateTime {
+    type Output = Self;
+
+    fn add(self, rhs: crate::Duration) -> Self::Output {
+        let naive = self.naive() + chrono::TimeDelta::days(rhs.num_days());
+
+        self.with_naive(naive) + rhs.exact()
+    }
+}
+
+impl crate::ser::Serialize for DateTime {
+    fn attr(&self) -> Option<String> {
+        self.tzid()
+            .map(|tzid| format!("TZID={}", crate::ser::param_value(tzid)))
+    }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    fn ical(&self) -> crate::Result<String> {
+        Ok(self.to_string())
+    }
+}
+
+/**
+ * See [3.3.4. Date](https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.4)
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Date {
     Date(chrono::NaiveDate),
     DateTime(DateTime),
@@ -178,6 +378,61 @@ impl Date {
     pub fn has_time(&self) -> bool {
         matches!(self, Self::DateTime(_))
     }
+
+    #[must_use]
+    pub fn naive(&self) -> chrono::NaiveDateTime {
+        match self {
+            Self::Date(date) => date.and_time(chrono::NaiveTime::MIN),
+            Self::DateTime(date_time) => date_time.naive(),
+        }
+    }
+
+    /**
+     * Returns a date of the same kind with `naive` as wall time, the time
+     * part is dropped for a `Date::Date`.
+     */
+    pub(crate) fn with_naive(&self, naive: chrono::NaiveDateTime) -> Self {
+        match self {
+            Self::Date(_) => Self::Date(naive.date()),
+            Self::DateTime(date_time) => Self::DateTime(date_time.with_naive(naive)),
+        }
+    }
+
+    /**
+     * Same as adding `rhs`, see [`DateTime::add_in`].
+     */
+    #[must_use]
+    pub fn add_in(self, rhs: crate::Duration, vtimezone: &crate::VTimezone) -> Self {
+        match self {
+            Self::Date(_) => self + rhs,
+            Self::DateTime(date_time) => Self::DateTime(date_time.add_in(rhs, vtimezone)),
+        }
+    }
+
+    /**
+     * Binds a floating date-time to the `tzid` time zone.
+     */
+    pub(crate) fn with_tzid(self, tzid: Option<&String>) -> Self {
+        match self {
+            Self::Date(date) => Self::Date(date),
+            Self::DateTime(date_time) => Self::DateTime(date_time.with_tzid(tzid)),
+        }
+    }
+
+    #[must_use]
+    pub fn tzid(&self) -> Option<&str> {
+        match self {
+            Self::Date(_) => None,
+            Self::DateTime(date_time) => date_time.tzid(),
+        }
+    }
+
+    fn key(&self) -> (chrono::NaiveDateTime, u8, Option<&str>) {
+        match self {
+            Self::Date(date) => (date.and_time(chrono::NaiveTime::MIN), 0, None),
+            Self::DateTime(date_time) => date_time.key(),
+        }
+    }
 }
 
 impl Default for Date {
@@ -207,6 +462,34 @@ impl std::fmt::Display for Date {
     }
 }
 
+/**
+ * A date is formatted as `2024-01-01`, see [`DateTime`] for the date-times.
+ */
+#[cfg(feature = "serde")]
+impl serde::Serialize for Date {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Date(date) => serializer.collect_str(&date.format("%Y-%m-%d")),
+            Self::DateTime(date_time) => date_time.serialize(serializer),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Date {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+
+        if let Ok(date) = chrono::NaiveDate::parse_from_str(&s, "%Y-%m-%d") {
+            Ok(Self::Date(date))
+        } else {
+            DateTime::from_iso(&s)
+                .map(Self::DateTime)
+                .map_err(serde::de::Error::custom)
+        }
+    }
+}
+
 impl From<DateTime> for Date {
     fn from(value: DateTime) -> Self {
         Date::DateTime(value)
@@ -225,6 +508,12 @@ impl From<chrono::DateTime<chrono::Local>> for Date {
     }
 }
 
+impl From<chrono::DateTime<chrono::Utc>> for Date {
+    fn from(value: chrono::DateTime<chrono::Utc>) -> Self {
+        Self::DateTime(value.into())
+    }
+}
+
 impl From<chrono::NaiveDateTime> for Date {
     fn from(value: chrono::NaiveDateTime) -> Self {
         Self::DateTime(value.into())
@@ -246,14 +535,13 @@ impl std::cmp::PartialOrd for Date {
     }
 }
 
+/**
+ * A date is ordered as the floating date-time at its midnight, before the
+ * date-times of the same time, see [`DateTime`].
+ */
 impl std::cmp::Ord for Date {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        match (self, other) {
-            (Self::Date(a), Self::Date(b)) => a.cmp(b),
-            (Self::DateTime(a), Self::DateTime(b)) => a.cmp(b),
-            (Self::Date(a), Self::DateTime(b)) => a.cmp(&b.date_naive()),
-            (Self::DateTime(a), Self::Date(b)) => a.date_naive().cmp(b),
-        }
+        self.key().cmp(&other.key())
     }
 }
 
@@ -268,11 +556,22 @@ impl std::ops::Add<chrono::TimeDelta> for Date {
     }
 }
 
+impl std::ops::Add<crate::Duration> for Date {
+    type Output = Self;
+
+    fn add(self, rhs: crate::Duration) -> Self::Output {
+        match self {
+            Self::Date(date) => Self::Date(date + rhs.to_time_delta()),
+            Self::DateTime(dt) => Self::DateTime(dt + rhs),
+        }
+    }
+}
+
 impl crate::ser::Serialize for Date {
     fn attr(&self) -> Option<String> {
         match self {
             Date::Date(_) => "VALUE=DATE".to_string().into(),
-            Date::DateTime(_) => None,
+            Date::DateTime(date_time) => date_time.attr(),
         }
     }
 
@@ -295,7 +594,89 @@ mod test {
         assert_eq!(crate::ser::ical(&date_time)?, "19700101T000000");
 
         let date_time = crate::DateTime::Local(chrono::DateTime::default());
-        assert_eq!(crate::ser::ical(&date_time)?, "19700101T010000Z");
+        assert_eq!(crate::ser::ical(&date_time)?, "19700101T000000Z");
+
+        let date_time = crate::DateTime::Utc(chrono::DateTime::default());
+        assert_eq!(crate::ser::ical(&date_time)?, "19700101T000000Z");
+
+        let date_time =
+            crate::DateTime::Zoned(chrono::NaiveDateTime::default(), "Europe/Paris".to_string());
+        assert_eq!(
+            crate::ser::ical(&date_time)?,
+            "TZID=Europe/Paris:19700101T000000"
+        );
+
+        let date = crate::Date::DateTime(date_time);
+        assert_eq!(
+            crate::ser::ical(&date)?,
+            "TZID=Europe/Paris:19700101T000000"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() -> crate::Result {
+        let naive = "2024-01-01T09:00:00".parse::<chrono::NaiveDateTime>()?;
+
+        let dates = [
+            (crate::Date::Date(naive.date()), "\"2024-01-01\""),
+            (
+                crate::DateTime::Naive(naive).into(),
+                "\"2024-01-01T09:00:00\"",
+            ),
+            (
+                crate::DateTime::Utc(naive.and_utc()).into(),
+                "\"2024-01-01T09:00:00Z\"",
+            ),
+            (
+                crate::DateTime::Zoned(naive, "Europe/Paris".to_string()).into(),
+                "\"2024-01-01T09:00:00[Europe/Paris]\"",
+            ),
+        ];
+
+        for (date, json) in dates {
+            assert_eq!(serde_json::to_string(&date).unwrap(), json);
+            assert_eq!(serde_json::from_str::<crate::Date>(json).unwrap(), date);
+        }
+
+        // a local date-time is written with its offset, read in the local time zone
+        let local = chrono::DateTime::parse_from_rfc3339("2024-01-01T09:00:00+01:00")?
+            .with_timezone(&chrono::Local);
+        let date = crate::Date::from(crate::DateTime::Local(local));
+        assert_eq!(
+            serde_json::from_str::<crate::Date>("\"2024-01-01T09:00:00+01:00\"").unwrap(),
+            date
+        );
+        let json = serde_json::to_string(&date).unwrap();
+        assert_eq!(serde_json::from_str::<crate::Date>(&json).unwrap(), date);
+
+        assert!(serde_json::from_str::<crate::Date>("\"20240101\"").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn ord() -> crate::Result {
+        use chrono::TimeZone as _;
+
+        let naive = "2024-01-01T09:00:00".parse::<chrono::NaiveDateTime>()?;
+
+        let utc = crate::DateTime::Utc(naive.and_utc());
+        let floating = crate::DateTime::Naive(naive);
+        let zoned = crate::DateTime::Zoned(naive, "America/New_York".to_string());
+
+        assert_ne!(utc.cmp(&floating), std::cmp::Ordering::Equal);
+        assert_ne!(floating.cmp(&zoned), std::cmp::Ordering::Equal);
+        assert_ne!(utc.cmp(&zoned), std::cmp::Ordering::Equal);
+
+        let local = crate::DateTime::Local(chrono::Local.from_utc_datetime(&naive));
+        assert!(local < crate::DateTime::Utc(naive.and_utc() + chrono::TimeDelta::minutes(1)));
+
+        let date = crate::Date::Date(naive.date());
+        assert!(date < crate::Date::DateTime(floating));
+        assert!(date > crate::Date::Date(naive.date().pred_opt().unwrap()));
 
         Ok(())
     }
@@ -0,0 +1,281 @@
+/**
+ * A duration, the weeks and days are nominal and the hours, minutes and
+ * seconds are exact: one day added across a DST change keeps the wall time.
+ *
+ * See [3.3.6. Duration](https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.6)
+ */
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Duration {
+    pub negative: bool,
+    pub weeks: u32,
+    pub days: u32,
+    pub hours: u32,
+    pub minutes: u32,
+    pub seconds: u32,
+}
+
+impl Duration {
+    #[must_use]
+    pub fn weeks(weeks: i64) -> Self {
+        Self {
+            negative: weeks < 0,
+            weeks: clamp(weeks),
+            ..Default::default()
+        }
+    }
+
+    #[must_use]
+    pub fn days(days: i64) -> Self {
+        Self {
+            negative: days < 0,
+            days: clamp(days),
+            ..Default::default()
+        }
+    }
+
+    /**
+     * The exact constructors normalize the value, `minutes(90)` is `PT1H30M`.
+     */
+    #[must_use]
+    pub fn hours(hours: i64) -> Self {
+        chrono::TimeDelta::hours(hours).into()
+    }
+
+    #[must_use]
+    pub fn minutes(minutes: i64) -> Self {
+        chrono::TimeDelta::minutes(minutes).into()
+    }
+
+    #[must_use]
+    pub fn seconds(seconds: i64) -> Self {
+        chrono::TimeDelta::seconds(seconds).into()
+    }
+
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.num_days() == 0 && self.exact().is_zero()
+    }
+
+    /**
+     * The nominal part of the duration, in days.
+     */
+    #[must_use]
+    pub fn num_days(&self) -> i64 {
+        let days = i64::from(self.weeks) * 7 + i64::from(self.days);
+
+        if self.negative { -days } else { days }
+    }
+
+    /**
+     * The exact part of the duration.
+     */
+    #[must_use]
+    pub fn exact(&self) -> chrono::TimeDelta {
+        let exact = chrono::TimeDelta::hours(self.hours.into())
+            + chrono::TimeDelta::minutes(self.minutes.into())
+            + chrono::TimeDelta::seconds(self.seconds.into());
+
+        if self.negative { -exact } else { exact }
+    }
+
+    /**
+     * Approximates the duration with days of 24 hours.
+     */
+    #[must_use]
+    pub fn to_time_delta(&self) -> chrono::TimeDelta {
+        chrono::TimeDelta::days(self.num_days()) + self.exact()
+    }
+}
+
+fn clamp(value: i64) -> u32 {
+    value.unsigned_abs().try_into().unwrap_or(u32::MAX)
+}
+
+/**
+ * The time delta is kept as an exact duration.
+ */
+impl From<chrono::TimeDelta> for Duration {
+    fn from(value: chrono::TimeDelta) -> Self {
+        let seconds = value.num_seconds().unsigned_abs();
+
+        Self {
+            negative: value < chrono::TimeDelta::zero(),
+            hours: (seconds / 3_600).try_into().unwrap_or(u32::MAX),
+            minutes: (seconds / 60 % 60) as u32,
+            seconds: (seconds % 60) as u32,
+            ..Default::default()
+        }
+    }
+}
+
+impl std::ops::Neg for Duration {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            negative: !self.negative,
+            ..self
+        }
+    }
+}
+
+impl TryFrom<String> for Duration {
+    type Error = crate::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<&str> for Duration {
+    type Error = crate::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl std::str::FromStr for Duration {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        match crate::parser::datatype::duration(s) {
+            Ok(("", duration)) => Ok(duration),
+            _ => Err(crate::Error::ParseDuration(format!(
+                "Invalid duration: {s}"
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for Duration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.negative && !self.is_zero() {
+            f.write_str("-")?;
+        }
+
+        f.write_str("P")?;
+
+        if self.weeks != 0 && self.days == 0 && self.exact().is_zero() {
+            return write!(f, "{}W", self.weeks);
+        }
+
+        let days = self.num_days().unsigned_abs();
+        if days != 0 {
+            write!(f, "{days}D")?;
+        }
+
+        if self.exact().is_zero() {
+            if days == 0 {
+                f.write_str("T0S")?;
+            }
+
+            return Ok(());
+        }
+
+        f.write_str("T")?;
+
+        if self.hours != 0 {
+            write!(f, "{}H", self.hours)?;
+        }
+        if self.minutes != 0 {
+            write!(f, "{}M", self.minutes)?;
+        }
+        if self.seconds != 0 {
+            write!(f, "{}S", self.seconds)?;
+        }
+
+        Ok(())
+    }
+}
+
+crate::ser::ical_for_tostring!(Duration);
+#[cfg(feature = "serde")]
+crate::ser::serde_for_ical!(Duration);
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn parse() -> crate::Result {
+        let duration = "P15DT5H0M20S".parse::<crate::Duration>()?;
+        assert_eq!(duration.num_days(), 15);
+        assert_eq!(
+            duration.exact(),
+            chrono::TimeDelta::hours(5) + chrono::TimeDelta::seconds(20)
+        );
+        assert_eq!(duration.to_string(), "P15DT5H20S");
+
+        assert_eq!("P7W".parse::<crate::Duration>()?, crate::Duration::weeks(7));
+        assert_eq!(
+            "-PT10M".parse::<crate::Duration>()?,
+            crate::Duration::minutes(-10)
+        );
+
+        assert!("P1Y".parse::<crate::Duration>().is_err());
+        assert!("P1M".parse::<crate::Duration>().is_err());
+        assert!("P1DT1Y".parse::<crate::Duration>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn ser() -> crate::Result {
+        assert_eq!(crate::ser::ical(&crate::Duration::weeks(2))?, "P2W");
+        assert_eq!(crate::ser::ical(&crate::Duration::days(-15))?, "-P15D");
+        assert_eq!(
+            crate::ser::ical(&crate::Duration::from(chrono::TimeDelta::minutes(150)))?,
+            "PT2H30M"
+        );
+        assert_eq!(crate::ser::ical(&crate::Duration::default())?, "PT0S");
+
+        Ok(())
+    }
+
+    #[test]
+    fn add() -> crate::Result {
+        let vtimezone = crate::VTimezone::try_from(
+            "BEGIN:VTIMEZONE\r
+TZID:Europe/Paris\r
+BEGIN:DAYLIGHT\r
+DTSTART:19810329T020000\r
+RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU\r
+TZOFFSETFROM:+0100\r
+TZOFFSETTO:+0200\r
+END:DAYLIGHT\r
+BEGIN:STANDARD\r
+DTSTART:19961027T030000\r
+RRULE:FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU\r
+TZOFFSETFROM:+0200\r
+TZOFFSETTO:+0100\r
+END:STANDARD\r
+END:VTIMEZONE\r
+",
+        )?;
+
+        // 2024-03-31 is the DST change in the Europe/Paris time zone
+        let dtstart = crate::Date::from(crate::DateTime::Zoned(
+            "2024-03-30T12:00:00".parse()?,
+            "Europe/Paris".to_string(),
+        ));
+
+        let end = dtstart.clone().add_in(crate::Duration::days(1), &vtimezone);
+        assert_eq!(end.format("%F %T").to_string(), "2024-03-31 12:00:00");
+
+        let end = dtstart
+            .clone()
+            .add_in("PT24H".parse::<crate::Duration>()?, &vtimezone);
+        assert_eq!(end.format("%F %T").to_string(), "2024-03-31 13:00:00");
+
+        // without offset, the exact part is added to the wall time
+        let end = dtstart.clone() + "PT24H".parse::<crate::Duration>()?;
+        assert_eq!(end.format("%F %T").to_string(), "2024-03-31 12:00:00");
+
+        let dtstart: crate::Date = "20240331".parse()?;
+        assert_eq!(
+            dtstart + crate::Duration::weeks(-1),
+            "20240324".parse::<crate::Date>()?
+        );
+
+        Ok(())
+    }
+}
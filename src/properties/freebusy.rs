@@ -0,0 +1,132 @@
+/**
+ * The periods of a `FREEBUSY` property, all of the same free/busy type.
+ *
+ * See [3.8.2.6. Free/Busy Time](https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.2.6)
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FreeBusy {
+    /** See [3.2.9. Free/Busy Time Type](https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.9) */
+    pub fbtype: Option<FbType>,
+    pub periods: Vec<crate::Period>,
+    pub params: crate::Params,
+}
+
+impl FreeBusy {
+    #[must_use]
+    pub fn new(fbtype: FbType, periods: Vec<crate::Period>) -> Self {
+        Self {
+            fbtype: Some(fbtype),
+            periods,
+            params: crate::Params::new(),
+        }
+    }
+
+    /**
+     * The free/busy type, `BUSY` if not specified.
+     */
+    #[must_use]
+    pub fn fbtype(&self) -> FbType {
+        self.fbtype.clone().unwrap_or(FbType::Busy)
+    }
+}
+
+impl TryFrom<crate::ContentLine> for FreeBusy {
+    type Error = crate::Error;
+
+    fn try_from(value: crate::ContentLine) -> Result<Self, Self::Error> {
+        let mut params = value.params;
+
+        Ok(Self {
+            fbtype: params.take_single("FBTYPE").map(FbType::from),
+            params,
+            ..value.value.parse()?
+        })
+    }
+}
+
+impl TryFrom<String> for FreeBusy {
+    type Error = crate::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<&str> for FreeBusy {
+    type Error = crate::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl std::str::FromStr for FreeBusy {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        let periods = s
+            .split(',')
+            .map(crate::parser::datatype::period)
+            .collect::<crate::Result<_>>()?;
+
+        Ok(Self {
+            periods,
+            ..Default::default()
+        })
+    }
+}
+
+impl crate::ser::Serialize for FreeBusy {
+    fn ical(&self) -> crate::Result<String> {
+        self.periods.ical()
+    }
+
+    fn attr(&self) -> Option<String> {
+        let mut params = self.params.clone();
+
+        if let Some(fbtype) = &self.fbtype {
+            params.append("FBTYPE".to_string(), fbtype.to_string());
+        }
+
+        if params.is_empty() {
+            None
+        } else {
+            params.ical().ok()
+        }
+    }
+}
+
+super::param!(
+    /**
+     * See [3.2.9. Free/Busy Time Type](https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.9)
+     */
+    FbType {
+        Free => "FREE",
+        Busy => "BUSY",
+        BusyUnavailable => "BUSY-UNAVAILABLE",
+        BusyTentative => "BUSY-TENTATIVE",
+    }
+);
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn parse() -> crate::Result {
+        let (_, content_line) = crate::parser::content_line(
+            "FREEBUSY;FBTYPE=BUSY-UNAVAILABLE:19970308T160000Z/PT8H30M,19970309T160000Z/19970309T180000Z\r\n",
+        )?;
+        let freebusy = crate::FreeBusy::try_from(content_line)?;
+
+        assert_eq!(freebusy.fbtype(), crate::FbType::BusyUnavailable);
+        assert_eq!(freebusy.periods.len(), 2);
+        assert!(freebusy.params.is_empty());
+
+        assert_eq!(
+            crate::ser::ical(&freebusy)?,
+            "FBTYPE=BUSY-UNAVAILABLE:19970308T160000Z/PT8H30M,19970309T160000Z/19970309T180000Z"
+        );
+
+        Ok(())
+    }
+}
@@ -1,11 +1,16 @@
 /**
  * See [3.8. Component Properties](https://datatracker.ietf.org/doc/html/rfc5545#section-3.8)
  */
+mod attachment;
+mod cal_address;
 mod class;
 mod date;
+mod duration;
+mod freebusy;
 mod geo;
 mod rdate;
 mod recur;
+mod recur_id;
 mod request_status;
 mod status;
 mod text;
@@ -15,15 +20,70 @@ mod uri;
 
 pub mod period;
 
+pub use attachment::*;
+pub use cal_address::*;
 pub use class::*;
 pub use date::*;
+pub use duration::*;
+pub use freebusy::*;
 pub use geo::*;
 pub use period::Period;
 pub use rdate::*;
 pub use recur::*;
+pub use recur_id::*;
 pub use request_status::*;
 pub use status::*;
 pub use text::*;
 pub use time_transparency::*;
 pub use trigger::Trigger;
 pub use uri::*;
+
+macro_rules! param {
+    ($(#[$meta:meta])* $name:ident { $($variant:ident => $value:literal,)* }) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, Eq, PartialEq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub enum $name {
+            $($variant,)*
+            Custom(String),
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                match value.to_ascii_uppercase().as_str() {
+                    $($value => Self::$variant,)*
+                    _ => Self::Custom(value),
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                value.to_string().into()
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = crate::Error;
+
+            fn from_str(s: &str) -> crate::Result<Self> {
+                Ok(s.into())
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let s = match self {
+                    $(Self::$variant => $value,)*
+                    Self::Custom(s) => s,
+                };
+
+                f.write_str(s)
+            }
+        }
+
+        crate::ser::ical_for_tostring!($name);
+    };
+}
+
+pub(crate) use param;
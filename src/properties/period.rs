@@ -2,6 +2,7 @@
  * See [3.3.9. Period of Time](https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.9)
  */
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Period {
     StartEnd(StartEnd),
     StartDur(StartDur),
@@ -9,12 +10,37 @@ pub enum Period {
 
 impl Period {
     #[must_use]
-    pub fn duration(&self) -> chrono::Duration {
+    pub fn start(&self) -> crate::DateTime {
         match self {
-            Self::StartEnd(StartEnd { start, end }) => *end - *start,
+            Self::StartEnd(StartEnd { start, .. }) | Self::StartDur(StartDur { start, .. }) => {
+                start.clone()
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn duration(&self) -> crate::Duration {
+        match self {
+            Self::StartEnd(StartEnd { start, end }) => (end.naive() - start.naive()).into(),
             Self::StartDur(StartDur { duration, .. }) => *duration,
         }
     }
+
+    /**
+     * Binds the floating bounds of the period to the `tzid` time zone.
+     */
+    pub(crate) fn with_tzid(self, tzid: Option<&String>) -> Self {
+        match self {
+            Self::StartEnd(StartEnd { start, end }) => Self::StartEnd(StartEnd {
+                start: start.with_tzid(tzid),
+                end: end.with_tzid(tzid),
+            }),
+            Self::StartDur(StartDur { start, duration }) => Self::StartDur(StartDur {
+                start: start.with_tzid(tzid),
+                duration,
+            }),
+        }
+    }
 }
 
 impl TryFrom<String> for Period {
@@ -49,7 +75,9 @@ impl std::cmp::PartialOrd for Period {
 
 impl std::cmp::Ord for Period {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.duration().cmp(&other.duration())
+        self.duration()
+            .to_time_delta()
+            .cmp(&other.duration().to_time_delta())
     }
 }
 
@@ -67,6 +95,7 @@ impl std::fmt::Display for Period {
 crate::ser::ical_for_tostring!(Period);
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StartEnd {
     pub start: crate::DateTime,
     pub end: crate::DateTime,
@@ -80,8 +109,8 @@ impl std::cmp::PartialOrd for StartEnd {
 
 impl std::cmp::Ord for StartEnd {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        let a = self.end - self.start;
-        let b = other.end - other.start;
+        let a = self.end.naive() - self.start.naive();
+        let b = other.end.naive() - other.start.naive();
 
         a.cmp(&b)
     }
@@ -96,9 +125,10 @@ impl std::fmt::Display for StartEnd {
 crate::ser::ical_for_tostring!(StartEnd);
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StartDur {
     pub start: crate::DateTime,
-    pub duration: chrono::Duration,
+    pub duration: crate::Duration,
 }
 
 impl std::cmp::PartialOrd for StartDur {
@@ -109,8 +139,8 @@ impl std::cmp::PartialOrd for StartDur {
 
 impl std::cmp::Ord for StartDur {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        let a = self.start - self.duration;
-        let b = other.start - other.duration;
+        let a = self.start.naive() - self.duration.to_time_delta();
+        let b = other.start.naive() - other.duration.to_time_delta();
 
         a.cmp(&b)
     }
@@ -139,9 +169,9 @@ mod test {
 
         let period = crate::Period::StartDur(crate::period::StartDur {
             start: crate::DateTime::default(),
-            duration: chrono::TimeDelta::hours(5),
+            duration: crate::Duration::hours(5),
         });
-        assert_eq!(crate::ser::ical(&period)?, "19700101T000000/PT18000S");
+        assert_eq!(crate::ser::ical(&period)?, "19700101T000000/PT5H");
 
         Ok(())
     }
@@ -1,4 +1,5 @@
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RDate {
     Date(Vec<crate::Date>),
     Period(Vec<crate::Period>),
@@ -15,33 +16,61 @@ impl RDate {
     pub fn new() -> Self {
         Self::Date(Vec::new())
     }
+
+    /**
+     * Returns the `TZID` of the first value.
+     */
+    #[must_use]
+    pub fn tzid(&self) -> Option<&str> {
+        match self {
+            Self::Date(dates) => dates.first()?.tzid(),
+            Self::Period(periods) => match periods.first()? {
+                crate::Period::StartEnd(crate::period::StartEnd { start, .. })
+                | crate::Period::StartDur(crate::period::StartDur { start, .. }) => start.tzid(),
+            },
+        }
+    }
 }
 
 impl std::fmt::Display for RDate {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = match self {
-            RDate::Date(date) => format!(
-                "VALUE=DATE:{}",
-                date.iter()
-                    .map(ToString::to_string)
-                    .collect::<Vec<_>>()
-                    .join(",")
-            ),
-            RDate::Period(period) => format!(
-                "VALUE=PERIOD:{}",
-                period
-                    .iter()
-                    .map(ToString::to_string)
-                    .collect::<Vec<_>>()
-                    .join(",")
-            ),
+            RDate::Date(date) => date
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(","),
+            RDate::Period(period) => period
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(","),
         };
 
         f.write_str(&s)
     }
 }
 
-crate::ser::ical_for_tostring!(RDate);
+impl crate::ser::Serialize for RDate {
+    fn attr(&self) -> Option<String> {
+        match self {
+            RDate::Date(date) => date.first().and_then(crate::Date::attr),
+            RDate::Period(_) => {
+                let mut attr = "VALUE=PERIOD".to_string();
+
+                if let Some(tzid) = self.tzid() {
+                    attr.push_str(&format!(";TZID={}", crate::ser::param_value(tzid)));
+                }
+
+                Some(attr)
+            }
+        }
+    }
+
+    fn ical(&self) -> crate::Result<String> {
+        Ok(self.to_string())
+    }
+}
 
 #[cfg(test)]
 mod test {
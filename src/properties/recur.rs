@@ -20,13 +20,13 @@ pub struct Recur {
     #[serialize(rename = "BYMONTHDAY")]
     pub by_monthday: Vec<i8>,
     #[serialize(rename = "BYYEARDAY")]
-    pub by_yearday: Vec<i8>,
+    pub by_yearday: Vec<i16>,
     #[serialize(rename = "BYWEEKNO")]
     pub by_weekno: Vec<i8>,
     #[serialize(rename = "BYMONTH")]
     pub by_month: Vec<i8>,
     #[serialize(rename = "BYSETPOS")]
-    pub by_setpos: Vec<i8>,
+    pub by_setpos: Vec<i16>,
     pub wkst: Option<Weekday>,
 }
 
@@ -54,6 +54,9 @@ impl std::str::FromStr for Recur {
     }
 }
 
+#[cfg(feature = "serde")]
+crate::ser::serde_for_ical!(Recur);
+
 impl std::ops::Add<crate::Date> for crate::Recur {
     type Output = crate::Date;
 
@@ -95,6 +98,8 @@ impl std::ops::Add<crate::DateTime> for crate::Recur {
         match rhs {
             crate::DateTime::Naive(date) => crate::DateTime::Naive(self + date),
             crate::DateTime::Local(date) => crate::DateTime::Local(self + date),
+            crate::DateTime::Utc(date) => crate::DateTime::Utc((self + date.naive_utc()).and_utc()),
+            crate::DateTime::Zoned(date, tzid) => crate::DateTime::Zoned(self + date, tzid),
         }
     }
 }
@@ -136,6 +141,7 @@ impl std::ops::Add<chrono::NaiveDateTime> for crate::Recur {
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Freq {
     Secondly,
     Minutely,
@@ -202,6 +208,7 @@ impl std::fmt::Display for Freq {
 crate::ser::ical_for_tostring!(Freq);
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WeekdayNum {
     pub weekday: Weekday,
     pub ord: Option<i8>,
@@ -246,6 +253,7 @@ impl std::fmt::Display for WeekdayNum {
 crate::ser::ical_for_tostring!(WeekdayNum);
 
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Weekday {
     Sunday,
     Monday,
@@ -300,6 +308,34 @@ impl std::fmt::Display for Weekday {
 
 crate::ser::ical_for_tostring!(Weekday);
 
+impl From<&Weekday> for chrono::Weekday {
+    fn from(value: &Weekday) -> Self {
+        match value {
+            Weekday::Sunday => Self::Sun,
+            Weekday::Monday => Self::Mon,
+            Weekday::Tuesday => Self::Tue,
+            Weekday::Wenesday => Self::Wed,
+            Weekday::Thurday => Self::Thu,
+            Weekday::Friday => Self::Fri,
+            Weekday::Saturday => Self::Sat,
+        }
+    }
+}
+
+impl From<chrono::Weekday> for Weekday {
+    fn from(value: chrono::Weekday) -> Self {
+        match value {
+            chrono::Weekday::Sun => Self::Sunday,
+            chrono::Weekday::Mon => Self::Monday,
+            chrono::Weekday::Tue => Self::Tuesday,
+            chrono::Weekday::Wed => Self::Wenesday,
+            chrono::Weekday::Thu => Self::Thurday,
+            chrono::Weekday::Fri => Self::Friday,
+            chrono::Weekday::Sat => Self::Saturday,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     #[test]
@@ -338,4 +374,21 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() -> crate::Result {
+        let recur = "FREQ=WEEKLY;UNTIL=20240131T000000Z;BYDAY=MO,-1FR".parse::<crate::Recur>()?;
+        let json = serde_json::to_string(&recur).unwrap();
+        assert_eq!(
+            json,
+            "\"FREQ=WEEKLY;UNTIL=20240131T000000Z;INTERVAL=1;BYDAY=MO,-1FR\""
+        );
+        assert_eq!(serde_json::from_str::<crate::Recur>(&json).unwrap(), recur);
+
+        let duration = serde_json::to_string(&crate::Duration::minutes(-90)).unwrap();
+        assert_eq!(duration, "\"-PT1H30M\"");
+
+        Ok(())
+    }
 }
@@ -0,0 +1,172 @@
+/**
+ * See [3.8.4.4. Recurrence ID](https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.4.4)
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecurId {
+    pub date: crate::Date,
+    pub range: Option<Range>,
+}
+
+impl RecurId {
+    /**
+     * Returns `true` if this identifier designates the instance starting
+     * at `date`, or also the following ones for a `THISANDFUTURE` range.
+     *
+     * The other ranges only designate the instance at `date`.
+     */
+    #[must_use]
+    pub fn matches(&self, date: &crate::Date) -> bool {
+        match self.range {
+            Some(Range::ThisAndFuture) => self.date.naive() <= date.naive(),
+            Some(Range::Other(_)) | None => self.date.naive() == date.naive(),
+        }
+    }
+}
+
+impl From<crate::Date> for RecurId {
+    fn from(value: crate::Date) -> Self {
+        Self {
+            date: value,
+            range: None,
+        }
+    }
+}
+
+impl TryFrom<String> for RecurId {
+    type Error = crate::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<&str> for RecurId {
+    type Error = crate::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl std::str::FromStr for RecurId {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        s.parse::<crate::Date>().map(Self::from)
+    }
+}
+
+impl crate::ser::Serialize for RecurId {
+    fn attr(&self) -> Option<String> {
+        let attr = [
+            self.range.as_ref().map(|x| format!("RANGE={x}")),
+            self.date.attr(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+        if attr.is_empty() {
+            None
+        } else {
+            Some(attr.join(";"))
+        }
+    }
+
+    fn ical(&self) -> crate::Result<String> {
+        self.date.ical()
+    }
+}
+
+/**
+ * See [3.2.13. Recurrence Identifier Range](https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.13)
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Range {
+    ThisAndFuture,
+    /** The deprecated `THISANDPRIOR` of RFC 2445 or an unknown value, kept as is */
+    Other(String),
+}
+
+impl TryFrom<String> for Range {
+    type Error = crate::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<&str> for Range {
+    type Error = crate::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl std::str::FromStr for Range {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        match s {
+            "" => Err(crate::Error::Range(s.to_string())),
+            "THISANDFUTURE" => Ok(Self::ThisAndFuture),
+            _ => Ok(Self::Other(s.to_string())),
+        }
+    }
+}
+
+impl std::fmt::Display for Range {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ThisAndFuture => f.write_str("THISANDFUTURE"),
+            Self::Other(range) => f.write_str(range),
+        }
+    }
+}
+
+crate::ser::ical_for_tostring!(Range);
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn ser() -> crate::Result {
+        let recurid = crate::RecurId::from(crate::Date::Date(chrono::NaiveDate::default()));
+        assert_eq!(crate::ser::ical(&recurid)?, "VALUE=DATE:19700101");
+
+        let recurid = crate::RecurId {
+            date: "19960120T120000".parse()?,
+            range: Some(crate::Range::ThisAndFuture),
+        };
+        assert_eq!(
+            crate::ser::ical(&recurid)?,
+            "RANGE=THISANDFUTURE:19960120T120000"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse() -> crate::Result {
+        let (_, content_line) =
+            crate::parser::content_line("RECURRENCE-ID;RANGE=THISANDPRIOR:19960120T120000Z\r\n")?;
+        let recurid = crate::parser::recurid(content_line)?;
+
+        assert_eq!(
+            recurid.range,
+            Some(crate::Range::Other("THISANDPRIOR".to_string()))
+        );
+        assert!(recurid.matches(&"19960120T120000Z".parse()?));
+        assert!(!recurid.matches(&"19960121T120000Z".parse()?));
+        assert_eq!(
+            crate::ser::ical(&recurid)?,
+            "RANGE=THISANDPRIOR:19960120T120000Z"
+        );
+
+        assert!("".parse::<crate::Range>().is_err());
+
+        Ok(())
+    }
+}
@@ -1,18 +1,17 @@
-use std::collections::BTreeMap;
-
 /**
  * See [3.3.11. Text](https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.11)
  */
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Text {
-    pub params: BTreeMap<String, String>,
+    pub params: crate::Params,
     pub text: String,
 }
 
 impl Text {
     pub fn from(text: &str) -> Self {
         Self {
-            params: BTreeMap::new(),
+            params: crate::Params::new(),
             text: text.to_string(),
         }
     }
@@ -44,7 +43,7 @@ impl std::ops::Deref for Text {
 impl From<String> for Text {
     fn from(value: String) -> Self {
         Self {
-            params: BTreeMap::new(),
+            params: crate::Params::new(),
             text: value,
         }
     }
@@ -3,14 +3,15 @@
  */
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Trigger {
     DateTime(crate::DateTime),
-    Duration(chrono::Duration),
+    Duration(crate::Duration),
 }
 
 impl Default for Trigger {
     fn default() -> Self {
-        Self::Duration(chrono::Duration::zero())
+        Self::Duration(crate::Duration::default())
     }
 }
 
@@ -28,7 +29,7 @@ impl std::str::FromStr for Trigger {
         if let Ok(dt) = crate::DateTime::from_str(s) {
             Ok(Self::DateTime(dt))
         } else {
-            crate::parse_duration(s).map(Self::Duration)
+            crate::Duration::from_str(s).map(Self::Duration)
         }
     }
 }
@@ -61,8 +62,8 @@ mod test {
             "VALUE=DATE-TIME:19980101T050000Z"
         );
 
-        let trigger = crate::Trigger::Duration(chrono::Duration::days(-15));
-        assert_eq!(crate::ser::ical(&trigger)?, "VALUE=DURATION:-PT1296000S");
+        let trigger = crate::Trigger::Duration(crate::Duration::days(-15));
+        assert_eq!(crate::ser::ical(&trigger)?, "VALUE=DURATION:-P15D");
 
         Ok(())
     }
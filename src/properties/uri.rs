@@ -1,11 +1,10 @@
-use std::collections::BTreeMap;
-
 /**
  * See [3.3.13. URI](https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.13)
  */
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Uri {
-    pub params: BTreeMap<String, String>,
+    pub params: crate::Params,
     pub uri: String,
 }
 
@@ -35,7 +34,7 @@ impl std::ops::Deref for Uri {
 impl From<String> for Uri {
     fn from(value: String) -> Self {
         Self {
-            params: BTreeMap::new(),
+            params: crate::Params::new(),
             uri: value,
         }
     }
@@ -44,7 +43,7 @@ impl From<String> for Uri {
 impl From<&str> for Uri {
     fn from(value: &str) -> Self {
         Self {
-            params: BTreeMap::new(),
+            params: crate::Params::new(),
             uri: value.to_string(),
         }
     }
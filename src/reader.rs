@@ -0,0 +1,513 @@
+/*!
+ * Incremental parsing of a calendar stream.
+ */
+
+/**
+ * Reads the components of a calendar one by one.
+ *
+ * Only one component is kept in memory at a time, the properties of the
+ * calendar itself are accumulated and available with
+ * [`Reader::calendar`]. The unfolded lines are limited to
+ * [`Reader::max_line_length`] bytes.
+ *
+ * ```
+ * let input = "BEGIN:VCALENDAR\r
+ * VERSION:2.0\r
+ * PRODID:-//ikal//reader//EN\r
+ * BEGIN:VEVENT\r
+ * UID:1\r
+ * DTSTAMP:20240101T000000Z\r
+ * DTSTART:20240101T090000\r
+ * END:VEVENT\r
+ * END:VCALENDAR\r
+ * ";
+ *
+ * let mut reader = ikal::reader::Reader::new(input.as_bytes());
+ *
+ * for component in &mut reader {
+ *     if let ikal::Component::Event(vevent) = component? {
+ *         assert_eq!(vevent.uid.as_ref(), "1");
+ *     }
+ * }
+ *
+ * assert_eq!(reader.calendar()?.version.as_ref(), "2.0");
+ * # Ok::<(), ikal::Error>(())
+ * ```
+ */
+pub struct Reader<R> {
+    reader: R,
+    next: Option<(usize, Vec<u8>)>,
+    lines: usize,
+    max_line_length: usize,
+    properties: Vec<crate::ContentLine>,
+}
+
+impl<R: std::io::BufRead> Reader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            next: None,
+            lines: 0,
+            max_line_length: 1 << 20,
+            properties: Vec::new(),
+        }
+    }
+
+    /**
+     * Sets the maximum length of an unfolded line in bytes, 1 MiB by
+     * default. A longer line is skipped and reported as an error.
+     */
+    #[must_use]
+    pub fn max_line_length(mut self, max_line_length: usize) -> Self {
+        self.max_line_length = max_line_length;
+        self
+    }
+
+    /**
+     * Returns the calendar with the properties read so far, without its
+     * components.
+     */
+    pub fn calendar(&self) -> crate::Result<crate::VCalendar> {
+        self.properties.clone().try_into()
+    }
+
+    /**
+     * Next physical line with its number.
+     */
+    fn physical_line(&mut self) -> crate::Result<Option<(usize, Vec<u8>)>> {
+        use std::io::{BufRead as _, Read as _};
+
+        let mut line = Vec::new();
+        // room for the line ending
+        let limit = self.max_line_length.saturating_add(2) as u64;
+
+        if (&mut self.reader)
+            .take(limit)
+            .read_until(b'\n', &mut line)?
+            == 0
+        {
+            return Ok(None);
+        }
+
+        self.lines += 1;
+
+        let complete = line.last() == Some(&b'\n');
+
+        while line.last().is_some_and(|x| *x == b'\n' || *x == b'\r') {
+            line.pop();
+        }
+
+        if line.len() > self.max_line_length {
+            if !complete {
+                self.skip_line()?;
+            }
+
+            return Err(self.too_long(self.lines));
+        }
+
+        Ok(Some((self.lines, line)))
+    }
+
+    /**
+     * Discards the rest of the current physical line.
+     */
+    fn skip_line(&mut self) -> crate::Result {
+        loop {
+            let buf = self.reader.fill_buf()?;
+
+            if buf.is_empty() {
+                return Ok(());
+            }
+
+            match buf.iter().position(|x| *x == b'\n') {
+                Some(position) => {
+                    self.reader.consume(position + 1);
+                    return Ok(());
+                }
+                None => {
+                    let len = buf.len();
+                    self.reader.consume(len);
+                }
+            }
+        }
+    }
+
+    fn too_long(&self, line: usize) -> crate::Error {
+        crate::Error::Parse(crate::ParseError {
+            line,
+            column: 1,
+            path: "VCALENDAR".to_string(),
+            property: None,
+            expected: format!("Line longer than {} bytes", self.max_line_length),
+        })
+    }
+
+    /**
+     * Next unfolded line with the number of its first physical line.
+     */
+    fn line(&mut self) -> crate::Result<Option<(usize, String)>> {
+        let (n, mut line) = match self.next.take() {
+            Some(line) => line,
+            None => match self.physical_line()? {
+                Some(line) => line,
+                None => return Ok(None),
+            },
+        };
+
+        let mut too_long = false;
+
+        loop {
+            match self.physical_line()? {
+                Some((_, next)) if next.first().is_some_and(|x| *x == b' ' || *x == b'\t') => {
+                    if line.len() + next.len() - 1 > self.max_line_length {
+                        too_long = true;
+                        line.clear();
+                    }
+                    if !too_long {
+                        line.extend_from_slice(&next[1..]);
+                    }
+                }
+                next => {
+                    self.next = next;
+                    break;
+                }
+            }
+        }
+
+        if too_long {
+            return Err(self.too_long(n));
+        }
+
+        String::from_utf8(line)
+            .map(|line| Some((n, line)))
+            .map_err(|err| crate::Error::Parser(err.to_string()))
+    }
+
+    /**
+     * Reads the lines of the `name` component until its end and parses it.
+     *
+     * An `END` not matching the open sub-components is reported once the
+     * component is read, so the following components are still read.
+     */
+    fn component(&mut self, name: &str, begin: usize) -> crate::Result<crate::Component> {
+        let mut input = format!("BEGIN:{name}\r\n");
+        let mut lines = vec![begin];
+        let mut stack = vec![name.to_string()];
+        let mut error = None;
+
+        loop {
+            let Some((n, line)) = self.line()? else {
+                return Err(crate::Error::Parser(format!("Missing END:{name}")));
+            };
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(begin) = line.strip_prefix("BEGIN:") {
+                stack.push(begin.to_string());
+            } else if let Some(end) = line.strip_prefix("END:") {
+                let path = stack.join(" > ");
+                let expected = match stack.iter().rposition(|x| x == end) {
+                    Some(position) if position == stack.len() - 1 => None,
+                    Some(position) => {
+                        let expected = format!("Missing END:{}", stack[stack.len() - 1]);
+                        stack.truncate(position + 1);
+                        Some(expected)
+                    }
+                    None => Some(format!("Unexpected END:{end}")),
+                };
+
+                if let Some(expected) = expected {
+                    error.get_or_insert(crate::ParseError {
+                        line: n,
+                        column: 1,
+                        path,
+                        property: None,
+                        expected,
+                    });
+
+                    if stack.last().is_none_or(|x| x != end) {
+                        continue;
+                    }
+                }
+
+                stack.pop();
+            }
+
+            input.push_str(&line);
+            input.push_str("\r\n");
+            lines.push(n);
+
+            if stack.is_empty() {
+                break;
+            }
+        }
+
+        if let Some(error) = error {
+            return Err(crate::Error::Parse(error));
+        }
+
+        match crate::parser::component(&input) {
+            Ok(("", component)) => Ok(component),
+            Ok(_) => Err(crate::Error::Parser(format!("Invalid component {name}"))),
+            Err(err) => match crate::parser::lenient::error(&input, err) {
+                crate::Error::Parse(mut err) => {
+                    err.line = lines.get(err.line - 1).copied().unwrap_or(err.line);
+                    Err(crate::Error::Parse(err))
+                }
+                err => Err(err),
+            },
+        }
+    }
+}
+
+impl<R: std::io::BufRead> Iterator for Reader<R> {
+    type Item = crate::Result<crate::Component>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (n, line) = match self.line() {
+                Ok(Some((n, line))) => match line.strip_prefix('\u{feff}') {
+                    Some(line) => (n, line.to_string()),
+                    None => (n, line),
+                },
+                Ok(None) => return None,
+                Err(err) => return Some(Err(err)),
+            };
+
+            if line.is_empty() || line == "BEGIN:VCALENDAR" || line.starts_with("END:") {
+                continue;
+            }
+
+            if let Some(name) = line.strip_prefix("BEGIN:") {
+                return Some(self.component(name, n));
+            }
+
+            match crate::parser::content_line(&format!("{line}\r\n")) {
+                Ok((_, content_line)) => self.properties.push(content_line),
+                Err(err) => {
+                    return Some(Err(crate::Error::Parse(crate::ParseError {
+                        line: n,
+                        column: 1,
+                        path: "VCALENDAR".to_string(),
+                        property: None,
+                        expected: crate::Error::from(err).to_string(),
+                    })));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn calendars() -> crate::Result {
+        let tests = std::path::Path::new("tests/calendars");
+
+        for entry in tests.read_dir().unwrap() {
+            let file = entry.unwrap().path();
+
+            if file.extension() != Some(std::ffi::OsStr::new("ics"))
+                || !file.with_extension("out").exists()
+            {
+                continue;
+            }
+
+            let input = std::fs::read_to_string(&file).unwrap();
+            let expected = crate::VCalendar::try_from(input.as_str())?;
+
+            let mut reader =
+                super::Reader::new(std::io::BufReader::with_capacity(7, input.as_bytes()));
+            let mut actual = crate::VCalendar::new();
+
+            for component in &mut reader {
+                match component? {
+                    crate::Component::Alarm(alarm) => actual.alarms.push(alarm),
+                    crate::Component::Event(event) => actual.events.push(event),
+                    crate::Component::Freebusy(freebusy) => actual.freebusy.push(freebusy),
+                    crate::Component::Journal(journal) => actual.journals.push(journal),
+                    crate::Component::Raw(raw) => actual.push_raw(raw),
+                    crate::Component::Todo(todo) => actual.todo.push(todo),
+                    crate::Component::Timezone(timezone) => actual.timezones.push(timezone),
+                }
+            }
+
+            actual = crate::VCalendar {
+                alarms: actual.alarms,
+                events: actual.events,
+                freebusy: actual.freebusy,
+                journals: actual.journals,
+                todo: actual.todo,
+                timezones: actual.timezones,
+                x_comp: actual.x_comp,
+                iana_comp: actual.iana_comp,
+                ..reader.calendar()?
+            };
+
+            similar_asserts::assert_eq!(actual, expected, "{file:?}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn folding() -> crate::Result {
+        let input = b"BEGIN:VCALENDAR\r
+VERSION:2.0\r
+PRODID:-//ikal//reader//EN\r
+BEGIN:VTODO\r
+UID:1\r
+DTSTAMP:20240101T000000Z\r
+SUMMARY:Une t\xc3\r
+ \xa2che\r
+\tpli\xc3\xa9e\r
+END:VTODO\r
+END:VCALENDAR\r
+";
+        let mut reader = super::Reader::new(std::io::BufReader::with_capacity(3, &input[..]));
+
+        let Some(Ok(crate::Component::Todo(vtodo))) = reader.next() else {
+            panic!("Missing todo");
+        };
+        assert_eq!(vtodo.summary.unwrap().as_ref(), "Une tâchepliée");
+        assert!(reader.next().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn line_endings() -> crate::Result {
+        let input = "\u{feff}BEGIN:VCALENDAR
+VERSION:2.0\r
+PRODID:-//ikal//reader//EN
+BEGIN:VTODO
+UID:1\r
+DTSTAMP:20240101T000000Z
+SUMMARY:Une
+ \ttâche
+END:VTODO\r
+END:VCALENDAR
+";
+        let mut reader = super::Reader::new(input.as_bytes());
+
+        let Some(Ok(crate::Component::Todo(vtodo))) = reader.next() else {
+            panic!("Missing todo");
+        };
+        assert_eq!(vtodo.summary.unwrap().as_ref(), "Une\ttâche");
+        assert!(reader.next().is_none());
+        assert_eq!(reader.calendar()?.version.as_ref(), "2.0");
+
+        Ok(())
+    }
+
+    #[test]
+    fn error() {
+        let input = "BEGIN:VCALENDAR\r
+BEGIN:VEVENT\r
+UID:1\r
+END:VEVENT\r
+BEGIN:VTODO\r
+UID:2\r
+DTSTAMP:20240101T000000Z\r
+END:VTODO\r
+BEGIN:VJOURNAL\r
+";
+        let mut reader = super::Reader::new(input.as_bytes());
+
+        let Some(Err(crate::Error::Parse(err))) = reader.next() else {
+            panic!("Invalid event parsed");
+        };
+        assert_eq!(err.line, 2);
+        assert_eq!(err.path, "VEVENT > DTSTAMP");
+        assert!(reader.next().is_some_and(|x| x.is_ok()));
+        assert!(reader.next().is_some_and(|x| x.is_err()));
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn unmatched_end() {
+        let input = "BEGIN:VCALENDAR\r
+BEGIN:VEVENT\r
+UID:1\r
+DTSTAMP:20240101T000000Z\r
+END:VALARM\r
+END:VEVENT\r
+BEGIN:VTODO\r
+UID:2\r
+DTSTAMP:20240101T000000Z\r
+BEGIN:VALARM\r
+END:VTODO\r
+BEGIN:VJOURNAL\r
+UID:3\r
+DTSTAMP:20240101T000000Z\r
+DTSTART:20240101T090000\r
+END:VJOURNAL\r
+END:VCALENDAR\r
+";
+        let mut reader = super::Reader::new(input.as_bytes());
+
+        let Some(Err(crate::Error::Parse(err))) = reader.next() else {
+            panic!("Invalid event parsed");
+        };
+        assert_eq!(err.line, 5);
+        assert_eq!(err.path, "VEVENT");
+        assert_eq!(err.expected, "Unexpected END:VALARM");
+
+        let Some(Err(crate::Error::Parse(err))) = reader.next() else {
+            panic!("Invalid todo parsed");
+        };
+        assert_eq!(err.line, 11);
+        assert_eq!(err.path, "VTODO > VALARM");
+        assert_eq!(err.expected, "Missing END:VALARM");
+
+        let next = reader.next();
+        assert!(
+            matches!(next, Some(Ok(crate::Component::Journal(_)))),
+            "{next:?}"
+        );
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn line_too_long() {
+        let input = format!(
+            "BEGIN:VCALENDAR\r
+X-LONG:{}\r
+BEGIN:VTODO\r
+UID:1\r
+DTSTAMP:20240101T000000Z\r
+DESCRIPTION:{}\r
+ {}\r
+END:VTODO\r
+BEGIN:VTODO\r
+UID:2\r
+DTSTAMP:20240101T000000Z\r
+DESCRIPTION:{}\r
+END:VTODO\r
+END:VCALENDAR\r
+",
+            "a".repeat(100),
+            "b".repeat(40),
+            "c".repeat(40),
+            "d".repeat(40),
+        );
+        let mut reader = super::Reader::new(std::io::BufReader::with_capacity(7, input.as_bytes()))
+            .max_line_length(64);
+
+        let Some(Err(crate::Error::Parse(err))) = reader.next() else {
+            panic!("Long line read");
+        };
+        assert_eq!(err.line, 2);
+        assert_eq!(err.expected, "Line longer than 64 bytes");
+
+        assert!(reader.next().is_some_and(|x| x.is_err()));
+
+        let Some(Ok(crate::Component::Todo(vtodo))) = reader.next() else {
+            panic!("Missing todo");
+        };
+        assert_eq!(vtodo.uid.as_ref(), "2");
+        assert!(reader.next().is_none());
+    }
+}
@@ -12,6 +12,86 @@ macro_rules! ical_for_tostring {
 
 pub(crate) use ical_for_tostring;
 
+/**
+ * Implements the serde traits with the iCalendar representation of the value
+ * as string.
+ */
+#[cfg(feature = "serde")]
+macro_rules! serde_for_ical {
+    ($ty: ty) => {
+        impl ::serde::Serialize for $ty {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let s = $crate::ser::ical(self).map_err(::serde::ser::Error::custom)?;
+
+                serializer.serialize_str(&s)
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $ty {
+            fn deserialize<D: ::serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                let s = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+
+                s.parse().map_err(::serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "serde")]
+pub(crate) use serde_for_ical;
+
+/**
+ * Field helpers for the foreign types, see `#[serde(with)]`.
+ */
+#[cfg(feature = "serde")]
+pub(crate) mod serde {
+    /**
+     * Bytes as base64 string.
+     */
+    pub(crate) mod base64 {
+        use base64::Engine as _;
+
+        pub(crate) fn serialize<S: ::serde::Serializer>(
+            data: &[u8],
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&base64::engine::general_purpose::STANDARD.encode(data))
+        }
+
+        pub(crate) fn deserialize<'de, D: ::serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Vec<u8>, D::Error> {
+            let s = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+
+            base64::engine::general_purpose::STANDARD
+                .decode(s)
+                .map_err(::serde::de::Error::custom)
+        }
+    }
+
+    /**
+     * UTC offset as ISO 8601 string, like `+01:00`.
+     */
+    pub(crate) mod fixed_offset {
+        pub(crate) fn serialize<S: ::serde::Serializer>(
+            offset: &chrono::FixedOffset,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.collect_str(offset)
+        }
+
+        pub(crate) fn deserialize<'de, D: ::serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<chrono::FixedOffset, D::Error> {
+            let s = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+
+            s.parse().map_err(::serde::de::Error::custom)
+        }
+    }
+}
+
 pub trait Serialize {
     fn component() -> Option<String> {
         None
@@ -22,6 +102,16 @@ pub trait Serialize {
     }
 
     fn ical(&self) -> crate::Result<String>;
+
+    /**
+     * Writes the serialized value to `writer`, components are written
+     * property by property.
+     */
+    fn write(&self, writer: &mut dyn std::io::Write) -> crate::Result {
+        writer.write_all(self.ical()?.as_bytes())?;
+
+        Ok(())
+    }
 }
 
 pub fn ical<T: Serialize>(value: &T) -> crate::Result<String> {
@@ -37,7 +127,23 @@ pub fn ical<T: Serialize>(value: &T) -> crate::Result<String> {
     Ok(s)
 }
 
+/**
+ * Same as [`ical`] but writes the result to `writer`, without building the
+ * whole document in memory.
+ *
+ * The writes are small, wrap unbuffered writers in a [`std::io::BufWriter`].
+ */
+pub fn write<T: Serialize, W: std::io::Write>(mut writer: W, value: &T) -> crate::Result {
+    if let Some(attr) = value.attr() {
+        writer.write_all(attr.as_bytes())?;
+        writer.write_all(b":")?;
+    }
+
+    value.write(&mut writer)
+}
+
 ical_for_tostring!(i8);
+ical_for_tostring!(i16);
 ical_for_tostring!(u8);
 ical_for_tostring!(u32);
 ical_for_tostring!(chrono::TimeDelta);
@@ -72,20 +178,60 @@ impl Serialize for String {
 }
 
 impl<T: Serialize> Serialize for Vec<T> {
-    fn ical(&self) -> crate::Result<String> {
-        let mut s = String::new();
+    /**
+     * The attributes shared by all the values.
+     */
+    fn attr(&self) -> Option<String> {
+        let attr = self.first()?.attr();
 
-        for x in self {
-            s.push_str(&x.ical()?);
-            s.push(',');
+        if self.iter().all(|x| x.attr() == attr) {
+            attr
+        } else {
+            None
         }
-        s.pop();
+    }
 
-        Ok(s)
+    fn component() -> Option<String> {
+        T::component()
+    }
+
+    fn write(&self, writer: &mut dyn std::io::Write) -> crate::Result {
+        if T::component().is_some() {
+            for x in self {
+                x.write(writer)?;
+            }
+        } else {
+            writer.write_all(self.ical()?.as_bytes())?;
+        }
+
+        Ok(())
+    }
+
+    fn ical(&self) -> crate::Result<String> {
+        let separator = if T::component().is_some() { "" } else { "," };
+
+        let values = self
+            .iter()
+            .map(Serialize::ical)
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        Ok(values.join(separator))
     }
 }
 
 impl<T: Serialize> Serialize for Option<T> {
+    fn component() -> Option<String> {
+        T::component()
+    }
+
+    fn write(&self, writer: &mut dyn std::io::Write) -> crate::Result {
+        if let Some(value) = &self {
+            value.write(writer)
+        } else {
+            Ok(())
+        }
+    }
+
     fn attr(&self) -> Option<String> {
         if let Some(value) = &self {
             value.attr()
@@ -119,12 +265,32 @@ impl<K: ToString, V: Serialize> Serialize for std::collections::BTreeMap<K, V> {
     }
 }
 
+/**
+ * See [3.3.11. Text](https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.11)
+ */
 pub(crate) fn escape(s: &str) -> String {
-    s.replace(';', "\\;")
+    s.replace('\\', "\\\\")
+        .replace(';', "\\;")
         .replace(',', "\\,")
         .replace('\n', "\\n")
 }
 
+/**
+ * Encodes a parameter value, quoted if it contains a `:`, `;` or `,`.
+ *
+ * See [3.2. Property Parameters](https://datatracker.ietf.org/doc/html/rfc5545#section-3.2)
+ * and [RFC 6868](https://datatracker.ietf.org/doc/html/rfc6868)
+ */
+pub(crate) fn param_value(s: &str) -> String {
+    let s = s.replace('^', "^^").replace('\n', "^n").replace('"', "^'");
+
+    if s.contains([':', ';', ',']) {
+        format!("\"{s}\"")
+    } else {
+        s
+    }
+}
+
 pub(crate) fn field<S: Serialize>(name: &str, value: &S) -> crate::Result<String> {
     let mut s = String::new();
 
@@ -132,39 +298,60 @@ pub(crate) fn field<S: Serialize>(name: &str, value: &S) -> crate::Result<String
 
     if S::component().is_some() {
         s.push_str(&ical);
-    } else {
-        if !ical.is_empty() {
-            s.push_str(name);
+    } else if !ical.is_empty() {
+        let mut line = name.to_string();
 
-            if let Some(attr) = value.attr() {
-                s.push(';');
-                s.push_str(&attr);
-            }
-            s.push(':');
-            s.push_str(&ical);
-            s.push_str("\r\n");
+        if let Some(attr) = value.attr() {
+            line.push(';');
+            line.push_str(&attr);
         }
+        line.push(':');
+        line.push_str(&ical);
 
-        if s.len() > 75 {
-            let lines = split(&s, 75);
-            s = lines.join("\r\n ");
-        }
+        s.push_str(&fold(&line));
+        s.push_str("\r\n");
     }
 
     Ok(s)
 }
 
-fn split(s: &str, sub_size: usize) -> Vec<&str> {
-    let mut v = Vec::with_capacity(s.len() / sub_size);
-    let mut cur = s;
+pub(crate) fn write_field<S: Serialize>(
+    writer: &mut dyn std::io::Write,
+    name: &str,
+    value: &S,
+) -> crate::Result {
+    if S::component().is_some() {
+        value.write(writer)
+    } else {
+        writer.write_all(field(name, value)?.as_bytes())?;
+
+        Ok(())
+    }
+}
+
+/**
+ * Folds `line` in lines of at most 75 octets, leading space included,
+ * without splitting a UTF-8 sequence.
+ *
+ * See [3.1. Content Lines](https://datatracker.ietf.org/doc/html/rfc5545#section-3.1)
+ */
+fn fold(line: &str) -> String {
+    const MAX_OCTETS: usize = 75;
+
+    let mut s = String::with_capacity(line.len() + line.len() / MAX_OCTETS * 3);
+    let mut octets = 0;
+
+    for c in line.chars() {
+        if octets + c.len_utf8() > MAX_OCTETS {
+            s.push_str("\r\n ");
+            octets = 1;
+        }
 
-    while !cur.is_empty() {
-        let (chunk, rest) = cur.split_at(std::cmp::min(sub_size, cur.len()));
-        v.push(chunk);
-        cur = rest;
+        s.push(c);
+        octets += c.len_utf8();
     }
 
-    v
+    s
 }
 
 #[cfg(test)]
@@ -186,6 +373,75 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn write() -> crate::Result {
+        struct Writer {
+            output: Vec<u8>,
+            largest: usize,
+        }
+
+        impl std::io::Write for Writer {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.largest = self.largest.max(buf.len());
+                self.output.write(buf)
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut vcalendar = crate::vcalendar! {
+            prodid: "-//ikal//test//EN",
+            version: "2.0",
+        }?;
+
+        for uid in 0..100 {
+            let mut vevent = crate::vevent! {
+                uid: uid.to_string(),
+                dtstamp: "20240101T000000Z",
+                dtstart: "20240101T090000",
+                summary: "Event",
+            }?;
+            vevent.alarms.push(crate::valarm! {
+                @display,
+                trigger: "-PT15M",
+                description: "Reminder",
+            }?);
+
+            vcalendar.events.push(vevent);
+        }
+
+        let mut writer = Writer {
+            output: Vec::new(),
+            largest: 0,
+        };
+        crate::ser::write(&mut writer, &vcalendar)?;
+
+        let ical = crate::ser::ical(&vcalendar)?;
+        similar_asserts::assert_eq!(String::from_utf8(writer.output).unwrap(), ical);
+        assert!(writer.largest <= 100, "{}", writer.largest);
+        assert!(ical.contains("\r\nBEGIN:VALARM\r\nACTION:DISPLAY\r\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fold_multibyte() -> crate::Result {
+        let text = crate::Text::from(&"日本語の説明".repeat(10));
+        let ical = crate::ser::field("DESCRIPTION", &text)?;
+
+        for line in ical.split("\r\n") {
+            assert!(line.len() <= 75, "{line}");
+        }
+        assert_eq!(
+            ical.replace("\r\n ", ""),
+            format!("DESCRIPTION:{}\r\n", &*text)
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn fixed_offset() -> crate::Result {
         use crate::ser::Serialize as _;
@@ -0,0 +1,413 @@
+/*!
+ * Search of the free time slots shared by several calendars.
+ */
+
+type Interval = (chrono::DateTime<chrono::Utc>, chrono::DateTime<chrono::Utc>);
+
+/**
+ * Finds the common free slots of several calendars and free/busy replies.
+ *
+ * The working hours and days are expressed in the time zone given with
+ * [`Finder::timezone`], UTC by default. A working time skipped by a daylight
+ * saving transition is moved to the end of the gap.
+ *
+ * ```
+ * let vcalendar = ikal::VCalendar::try_from(
+ *     "BEGIN:VCALENDAR\r
+ * VERSION:2.0\r
+ * PRODID:-//ikal//slots//EN\r
+ * BEGIN:VEVENT\r
+ * UID:1\r
+ * DTSTAMP:20240101T000000Z\r
+ * DTSTART:20240101T100000Z\r
+ * DTEND:20240101T113000Z\r
+ * END:VEVENT\r
+ * END:VCALENDAR\r
+ * ",
+ * )?;
+ *
+ * let slots = ikal::slots::Finder::new(
+ *     "2024-01-01T09:00:00Z".parse()?,
+ *     "2024-01-01T12:00:00Z".parse()?,
+ * )
+ * .duration(chrono::TimeDelta::hours(1))
+ * .calendar(&vcalendar)?
+ * .slots();
+ *
+ * assert_eq!(slots.len(), 1);
+ * assert_eq!(slots[0].to_string(), "20240101T090000Z/20240101T100000Z");
+ * # Ok::<(), ikal::Error>(())
+ * ```
+ *
+ * The finder is a query builder, it isn't serializable with the `serde`
+ * feature.
+ */
+#[derive(Clone, Debug)]
+pub struct Finder {
+    start: chrono::DateTime<chrono::Utc>,
+    end: chrono::DateTime<chrono::Utc>,
+    duration: chrono::TimeDelta,
+    working_hours: Option<(chrono::NaiveTime, chrono::NaiveTime)>,
+    working_days: Vec<crate::Weekday>,
+    timezone: Option<crate::VTimezone>,
+    busy: Vec<Interval>,
+}
+
+impl Finder {
+    /**
+     * Searches the slots between `start` and `end`.
+     */
+    #[must_use]
+    pub fn new(start: chrono::DateTime<chrono::Utc>, end: chrono::DateTime<chrono::Utc>) -> Self {
+        Self {
+            start,
+            end,
+            duration: chrono::TimeDelta::zero(),
+            working_hours: None,
+            working_days: Vec::new(),
+            timezone: None,
+            busy: Vec::new(),
+        }
+    }
+
+    /**
+     * Only keeps the slots lasting at least `duration`.
+     */
+    #[must_use]
+    pub fn duration(mut self, duration: chrono::TimeDelta) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /**
+     * Restricts the slots to the time between `start` and `end` of each day.
+     */
+    #[must_use]
+    pub fn working_hours(mut self, start: chrono::NaiveTime, end: chrono::NaiveTime) -> Self {
+        self.working_hours = Some((start, end));
+        self
+    }
+
+    /**
+     * Restricts the slots to these days of the week, all the days are
+     * worked by default.
+     */
+    #[must_use]
+    pub fn working_days(mut self, days: Vec<crate::Weekday>) -> Self {
+        self.working_days = days;
+        self
+    }
+
+    /**
+     * Sets the time zone of the working hours and days.
+     */
+    #[must_use]
+    pub fn timezone(mut self, vtimezone: crate::VTimezone) -> Self {
+        self.timezone = Some(vtimezone);
+        self
+    }
+
+    /**
+     * Adds the busy time of the events of `vcalendar`, see
+     * [`crate::VCalendar::freebusy`].
+     */
+    pub fn calendar(self, vcalendar: &crate::VCalendar) -> crate::Result<Self> {
+        let vfreebusy = vcalendar.freebusy(self.start, self.end, None)?;
+
+        self.freebusy(&vfreebusy)
+    }
+
+    /**
+     * Adds the busy periods of `vfreebusy`, of any type but `FREE`.
+     *
+     * The periods must be in UTC, as required by
+     * [3.8.2.6. Free/Busy Time](https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.2.6).
+     */
+    pub fn freebusy(mut self, vfreebusy: &crate::VFreebusy) -> crate::Result<Self> {
+        use crate::period::{StartDur, StartEnd};
+
+        for freebusy in &vfreebusy.freebusy {
+            if freebusy.fbtype() == crate::FbType::Free {
+                continue;
+            }
+
+            for period in &freebusy.periods {
+                let interval = match period {
+                    crate::Period::StartEnd(StartEnd {
+                        start: crate::DateTime::Utc(start),
+                        end: crate::DateTime::Utc(end),
+                    }) => (*start, *end),
+                    crate::Period::StartDur(StartDur {
+                        start: crate::DateTime::Utc(start),
+                        duration,
+                    }) => (*start, *start + duration.to_time_delta()),
+                    _ => return Err(crate::Error::FreeBusy(period.to_string())),
+                };
+
+                self.busy.push(interval);
+            }
+        }
+
+        Ok(self)
+    }
+
+    /**
+     * Returns the free slots, ordered by start.
+     */
+    #[must_use]
+    pub fn slots(&self) -> Vec<crate::Period> {
+        let mut free = Vec::new();
+        let mut start = self.start;
+
+        for (busy_start, busy_end) in crate::components::merge(self.busy.clone()) {
+            if start < busy_start.min(self.end) {
+                free.push((start, busy_start.min(self.end)));
+            }
+            start = start.max(busy_end);
+        }
+
+        if start < self.end {
+            free.push((start, self.end));
+        }
+
+        let free = free
+            .into_iter()
+            .flat_map(|(start, end)| self.working_time(start, end))
+            .collect();
+
+        crate::components::merge(free)
+            .into_iter()
+            .filter(|(start, end)| *end - *start >= self.duration)
+            .map(|(start, end)| {
+                crate::Period::StartEnd(crate::period::StartEnd {
+                    start: start.into(),
+                    end: end.into(),
+                })
+            })
+            .collect()
+    }
+
+    /**
+     * Splits the interval by the working time of each day.
+     */
+    fn working_time(
+        &self,
+        start: chrono::DateTime<chrono::Utc>,
+        end: chrono::DateTime<chrono::Utc>,
+    ) -> Vec<Interval> {
+        use chrono::Datelike as _;
+
+        if self.working_hours.is_none() && self.working_days.is_empty() {
+            return vec![(start, end)];
+        }
+
+        let local = |utc: chrono::DateTime<chrono::Utc>| {
+            let utc = utc.naive_utc();
+
+            match self.timezone.as_ref().and_then(|x| x.offset_at_utc(utc)) {
+                Some(offset) => utc + offset,
+                None => utc,
+            }
+        };
+        let utc = |naive: chrono::NaiveDateTime| match &self.timezone {
+            Some(vtimezone) => vtimezone.to_utc_clamped(naive),
+            None => Some(naive.and_utc()),
+        };
+
+        let first = local(start).date();
+        let last = local(end).date();
+
+        first
+            .iter_days()
+            .take_while(|day| *day <= last)
+            .filter(|day| {
+                self.working_days.is_empty()
+                    || self
+                        .working_days
+                        .contains(&crate::Weekday::from(day.weekday()))
+            })
+            .filter_map(|day| {
+                let (from, to) = match self.working_hours {
+                    Some((from, to)) => (day.and_time(from), day.and_time(to)),
+                    None => (
+                        day.and_time(chrono::NaiveTime::MIN),
+                        day.succ_opt()?.and_time(chrono::NaiveTime::MIN),
+                    ),
+                };
+
+                Some((utc(from)?.max(start), utc(to)?.min(end)))
+            })
+            .filter(|(start, end)| start < end)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    const PARIS: &str = "BEGIN:VTIMEZONE\r
+TZID:Europe/Paris\r
+BEGIN:DAYLIGHT\r
+DTSTART:19810329T020000\r
+RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU\r
+TZOFFSETFROM:+0100\r
+TZOFFSETTO:+0200\r
+END:DAYLIGHT\r
+BEGIN:STANDARD\r
+DTSTART:19961027T030000\r
+RRULE:FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU\r
+TZOFFSETFROM:+0200\r
+TZOFFSETTO:+0100\r
+END:STANDARD\r
+END:VTIMEZONE\r
+";
+
+    fn period(
+        start: chrono::DateTime<chrono::Utc>,
+        end: chrono::DateTime<chrono::Utc>,
+    ) -> crate::Period {
+        crate::Period::StartEnd(crate::period::StartEnd {
+            start: crate::DateTime::Utc(start),
+            end: crate::DateTime::Utc(end),
+        })
+    }
+
+    fn hours(start: u32, end: u32) -> (chrono::NaiveTime, chrono::NaiveTime) {
+        (
+            chrono::NaiveTime::from_hms_opt(start, 0, 0).unwrap(),
+            chrono::NaiveTime::from_hms_opt(end, 0, 0).unwrap(),
+        )
+    }
+
+    #[test]
+    fn slots() -> crate::Result {
+        let vcalendar = crate::VCalendar::try_from(format!(
+            "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+PRODID:-//ikal//test//EN\r
+{PARIS}BEGIN:VEVENT\r
+UID:meeting@example.com\r
+DTSTAMP:20240101T000000Z\r
+DTSTART;TZID=Europe/Paris:20240108T100000\r
+DTEND;TZID=Europe/Paris:20240108T113000\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:holiday@example.com\r
+DTSTAMP:20240101T000000Z\r
+DTSTART;VALUE=DATE:20240109\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:reminder@example.com\r
+DTSTAMP:20240101T000000Z\r
+DTSTART;TZID=Europe/Paris:20240110T090000\r
+DTEND;TZID=Europe/Paris:20240110T170000\r
+TRANSP:TRANSPARENT\r
+END:VEVENT\r
+END:VCALENDAR\r
+"
+        ))?;
+        let vtimezone = vcalendar.timezone("Europe/Paris").unwrap().clone();
+
+        let local = |day, hour, min| {
+            let naive = chrono::NaiveDate::from_ymd_opt(2024, 1, day)
+                .unwrap()
+                .and_hms_opt(hour, min, 0)
+                .unwrap();
+
+            vtimezone.to_utc(naive).unwrap()
+        };
+
+        let vfreebusy = crate::VFreebusy {
+            freebusy: vec![
+                crate::FreeBusy::new(
+                    crate::FbType::Busy,
+                    vec![period(local(8, 13, 0), local(8, 16, 30))],
+                ),
+                crate::FreeBusy::new(
+                    crate::FbType::Free,
+                    vec![period(local(10, 9, 0), local(10, 17, 0))],
+                ),
+            ],
+            ..Default::default()
+        };
+
+        let (from, to) = hours(9, 17);
+
+        let slots = super::Finder::new(local(8, 0, 0), local(14, 0, 0))
+            .duration(chrono::TimeDelta::hours(1))
+            .working_hours(from, to)
+            .working_days(vec![
+                crate::Weekday::Monday,
+                crate::Weekday::Tuesday,
+                crate::Weekday::Wenesday,
+                crate::Weekday::Thurday,
+                crate::Weekday::Friday,
+            ])
+            .timezone(vtimezone.clone())
+            .calendar(&vcalendar)?
+            .freebusy(&vfreebusy)?
+            .slots();
+
+        let expected = [
+            period(local(8, 9, 0), local(8, 10, 0)),
+            period(local(8, 11, 30), local(8, 13, 0)),
+            period(local(10, 9, 0), local(10, 17, 0)),
+            period(local(11, 9, 0), local(11, 17, 0)),
+            period(local(12, 9, 0), local(12, 17, 0)),
+        ];
+
+        assert_eq!(slots, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn dst_gap() -> crate::Result {
+        let vtimezone = crate::VTimezone::try_from(PARIS)?;
+        let (from, to) = hours(2, 5);
+
+        let slots = super::Finder::new(
+            "2024-03-30T00:00:00Z".parse()?,
+            "2024-04-01T00:00:00Z".parse()?,
+        )
+        .working_hours(from, to)
+        .timezone(vtimezone)
+        .slots();
+
+        // 02:00 doesn't exist the 31st, the day starts at 03:00 CEST
+        let expected = [
+            period(
+                "2024-03-30T01:00:00Z".parse()?,
+                "2024-03-30T04:00:00Z".parse()?,
+            ),
+            period(
+                "2024-03-31T01:00:00Z".parse()?,
+                "2024-03-31T03:00:00Z".parse()?,
+            ),
+        ];
+
+        assert_eq!(slots, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn freebusy_not_utc() -> crate::Result {
+        let vfreebusy = crate::VFreebusy {
+            freebusy: vec![crate::FreeBusy::try_from("20240108T090000/PT1H")?],
+            ..Default::default()
+        };
+
+        let finder = super::Finder::new(
+            "2024-01-08T00:00:00Z".parse()?,
+            "2024-01-09T00:00:00Z".parse()?,
+        )
+        .freebusy(&vfreebusy);
+
+        assert_eq!(
+            finder.err(),
+            Some(crate::Error::FreeBusy("20240108T090000/PT1H".to_string()))
+        );
+
+        Ok(())
+    }
+}
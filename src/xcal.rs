@@ -0,0 +1,525 @@
+/*!
+ * The XML format for iCalendar.
+ *
+ * xCal and jCal share the same model, a value is converted from and to its
+ * [`crate::jcal`] representation.
+ *
+ * Requires the `xcal` feature.
+ *
+ * ```
+ * let vcalendar = ikal::VCalendar {
+ *     version: "2.0".into(),
+ *     prodid: "-//ikal//xcal//EN".into(),
+ *
+ *     ..Default::default()
+ * };
+ *
+ * let xcal = ikal::xcal::to_string(&vcalendar)?;
+ * assert_eq!(
+ *     xcal,
+ *     r#"<?xml version="1.0" encoding="utf-8"?><icalendar xmlns="urn:ietf:params:xml:ns:icalendar-2.0"><vcalendar><properties><prodid><text>-//ikal//xcal//EN</text></prodid><version><text>2.0</text></version></properties><components></components></vcalendar></icalendar>"#
+ * );
+ *
+ * assert_eq!(ikal::xcal::from_str::<ikal::VCalendar>(&xcal)?, vcalendar);
+ * # Ok::<(), ikal::Error>(())
+ * ```
+ *
+ * See [RFC 6321](https://datatracker.ietf.org/doc/html/rfc6321)
+ */
+
+use serde_json::Value;
+
+const NAMESPACE: &str = "urn:ietf:params:xml:ns:icalendar-2.0";
+
+/**
+ * The parts of a recurrence rule, in the order of the schema.
+ *
+ * See [3.6.10. Recurrence Rule](https://datatracker.ietf.org/doc/html/rfc6321#section-3.6.10)
+ */
+const RECUR_PARTS: [&str; 14] = [
+    "freq",
+    "until",
+    "count",
+    "interval",
+    "bysecond",
+    "byminute",
+    "byhour",
+    "byday",
+    "bymonthday",
+    "byyearday",
+    "byweekno",
+    "bymonth",
+    "bysetpos",
+    "wkst",
+];
+
+/**
+ * Converts a component to an xCal document.
+ */
+pub fn to_string<T: crate::ser::Serialize>(value: &T) -> crate::Result<String> {
+    let jcal = crate::jcal::to_value(value)?;
+
+    let mut xml =
+        format!(r#"<?xml version="1.0" encoding="utf-8"?><icalendar xmlns="{NAMESPACE}">"#);
+    component(&mut xml, &jcal)?;
+    xml.push_str("</icalendar>");
+
+    Ok(xml)
+}
+
+/**
+ * Converts the first component of an xCal document to `T`.
+ */
+pub fn from_str<T: TryFrom<String, Error = crate::Error>>(s: &str) -> crate::Result<T> {
+    let document = Element::parse(s)?;
+
+    let component = document
+        .children
+        .iter()
+        .find(|x| x.name == "icalendar")
+        .and_then(|x| x.children.first())
+        .ok_or_else(|| invalid("icalendar root element"))?;
+
+    crate::jcal::from_value(&jcal(component)?)
+}
+
+/**
+ * The value type of a parameter.
+ *
+ * See [3.5. Parameters](https://datatracker.ietf.org/doc/html/rfc6321#section-3.5)
+ */
+fn param_type(name: &str) -> &'static str {
+    match name {
+        "delegated-from" | "delegated-to" | "member" | "sent-by" => "cal-address",
+        "altrep" | "dir" => "uri",
+        "rsvp" => "boolean",
+        _ => "text",
+    }
+}
+
+/**
+ * The names of the parts of a structured value.
+ */
+fn parts(name: &str) -> Option<&'static [&'static str]> {
+    match name {
+        "geo" => Some(&["latitude", "longitude"]),
+        "request-status" => Some(&["code", "description", "data"]),
+        _ => None,
+    }
+}
+
+fn component(xml: &mut String, value: &Value) -> crate::Result {
+    let Some(
+        [
+            Value::String(name),
+            Value::Array(properties),
+            Value::Array(components),
+        ],
+    ) = value.as_array().map(Vec::as_slice)
+    else {
+        return Err(invalid("component"));
+    };
+
+    xml.push_str(&format!("<{name}><properties>"));
+    for property in properties {
+        self::property(xml, property)?;
+    }
+    xml.push_str("</properties><components>");
+    for component in components {
+        self::component(xml, component)?;
+    }
+    xml.push_str(&format!("</components></{name}>"));
+
+    Ok(())
+}
+
+/**
+ * See [3.4. Properties](https://datatracker.ietf.org/doc/html/rfc6321#section-3.4)
+ */
+fn property(xml: &mut String, value: &Value) -> crate::Result {
+    let Some(
+        [
+            Value::String(name),
+            Value::Object(params),
+            Value::String(ty),
+            values @ ..,
+        ],
+    ) = value.as_array().map(Vec::as_slice)
+    else {
+        return Err(invalid("property"));
+    };
+
+    xml.push_str(&format!("<{name}>"));
+
+    if !params.is_empty() {
+        xml.push_str("<parameters>");
+
+        for (param, value) in params {
+            let ty = param_type(param);
+            let values = match value {
+                Value::Array(values) => values.as_slice(),
+                value => std::slice::from_ref(value),
+            };
+
+            xml.push_str(&format!("<{param}>"));
+            for value in values {
+                let value = text(value);
+
+                if ty == "boolean" {
+                    element(xml, ty, &value.to_lowercase());
+                } else {
+                    element(xml, ty, &value);
+                }
+            }
+            xml.push_str(&format!("</{param}>"));
+        }
+
+        xml.push_str("</parameters>");
+    }
+
+    for value in values {
+        match (value, parts(name)) {
+            (Value::Array(values), Some(parts)) => {
+                for (part, value) in parts.iter().zip(values) {
+                    element(xml, part, &text(value));
+                }
+            }
+            (Value::Object(rule), _) => {
+                let mut rule = rule.iter().collect::<Vec<_>>();
+                rule.sort_by_key(|(part, _)| {
+                    RECUR_PARTS
+                        .iter()
+                        .position(|x| x == part)
+                        .unwrap_or(RECUR_PARTS.len())
+                });
+
+                xml.push_str("<recur>");
+                for (part, value) in rule {
+                    let values = match value {
+                        Value::Array(values) => values.as_slice(),
+                        value => std::slice::from_ref(value),
+                    };
+
+                    for value in values {
+                        element(xml, part, &text(value));
+                    }
+                }
+                xml.push_str("</recur>");
+            }
+            (value, _) if ty == "period" => {
+                let period = text(value);
+                let (start, end) = period.split_once('/').unwrap_or((&period, ""));
+                let end_ty = if end.contains('P') { "duration" } else { "end" };
+
+                xml.push_str("<period>");
+                element(xml, "start", start);
+                element(xml, end_ty, end);
+                xml.push_str("</period>");
+            }
+            (value, _) => element(xml, ty, &text(value)),
+        }
+    }
+
+    xml.push_str(&format!("</{name}>"));
+
+    Ok(())
+}
+
+fn element(xml: &mut String, name: &str, text: &str) {
+    let text = quick_xml::escape::escape(text);
+
+    xml.push_str(&format!("<{name}>{text}</{name}>"));
+}
+
+fn text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
+
+/**
+ * The jCal representation of a component element.
+ */
+fn jcal(element: &Element) -> crate::Result<Value> {
+    let child = |name| {
+        element
+            .children
+            .iter()
+            .find(|x| x.name == name)
+            .map(|x| x.children.as_slice())
+            .unwrap_or_default()
+    };
+
+    let properties = child("properties")
+        .iter()
+        .map(property_jcal)
+        .collect::<crate::Result<_>>()?;
+    let components = child("components")
+        .iter()
+        .map(jcal)
+        .collect::<crate::Result<_>>()?;
+
+    Ok(Value::Array(vec![
+        Value::String(element.name.clone()),
+        Value::Array(properties),
+        Value::Array(components),
+    ]))
+}
+
+fn property_jcal(element: &Element) -> crate::Result<Value> {
+    let mut params = serde_json::Map::new();
+    let mut ty = None;
+    let mut values = Vec::new();
+
+    for child in &element.children {
+        match child.name.as_str() {
+            "parameters" => {
+                for param in &child.children {
+                    let text = |value: &Element| {
+                        if param_type(&param.name) == "boolean" {
+                            Value::String(value.text.to_uppercase())
+                        } else {
+                            Value::String(value.text.clone())
+                        }
+                    };
+
+                    let value = match param.children.as_slice() {
+                        [value] => text(value),
+                        values => values.iter().map(text).collect(),
+                    };
+
+                    params.insert(param.name.clone(), value);
+                }
+            }
+            "recur" => {
+                let mut rule = serde_json::Map::new();
+
+                for part in &child.children {
+                    let value = Value::String(part.text.clone());
+
+                    match rule.get_mut(&part.name) {
+                        Some(Value::Array(values)) => values.push(value),
+                        Some(previous) => *previous = Value::Array(vec![previous.take(), value]),
+                        None => {
+                            rule.insert(part.name.clone(), value);
+                        }
+                    }
+                }
+
+                ty = Some("recur");
+                values.push(Value::Object(rule));
+            }
+            "period" => {
+                let period = child
+                    .children
+                    .iter()
+                    .map(|x| x.text.as_str())
+                    .collect::<Vec<_>>();
+
+                ty = Some("period");
+                values.push(Value::String(period.join("/")));
+            }
+            name if parts(&element.name).is_some_and(|x| x.contains(&name)) => {
+                ty = Some(if element.name == "geo" {
+                    "float"
+                } else {
+                    "text"
+                });
+
+                if values.is_empty() {
+                    values.push(Value::Array(Vec::new()));
+                }
+                if let Some(Value::Array(parts)) = values.last_mut() {
+                    parts.push(Value::String(child.text.clone()));
+                }
+            }
+            name => {
+                let value = if name == "boolean" {
+                    Value::Bool(child.text.eq_ignore_ascii_case("true"))
+                } else {
+                    Value::String(child.text.clone())
+                };
+
+                ty = Some(child.name.as_str());
+                values.push(value);
+            }
+        }
+    }
+
+    let mut property = vec![
+        Value::String(element.name.clone()),
+        Value::Object(params),
+        Value::String(ty.unwrap_or("unknown").to_string()),
+    ];
+    property.extend(values);
+
+    Ok(Value::Array(property))
+}
+
+fn invalid(expected: &str) -> crate::Error {
+    crate::Error::XCal(format!("expected {expected}"))
+}
+
+/**
+ * A minimal XML tree, the text of the elements with children is ignored.
+ */
+#[derive(Debug, Default)]
+struct Element {
+    name: String,
+    text: String,
+    children: Vec<Element>,
+}
+
+impl Element {
+    fn new(name: &[u8]) -> Self {
+        Self {
+            name: String::from_utf8_lossy(name).into_owned(),
+            ..Default::default()
+        }
+    }
+
+    /**
+     * Parses an XML document, the returned element is the document itself.
+     */
+    fn parse(s: &str) -> crate::Result<Self> {
+        use quick_xml::events::Event;
+
+        let error = |err: quick_xml::Error| crate::Error::XCal(err.to_string());
+        let mut reader = quick_xml::Reader::from_str(s);
+        let mut stack = vec![Self::default()];
+
+        loop {
+            match reader.read_event().map_err(error)? {
+                Event::Start(start) => stack.push(Self::new(start.local_name().as_ref())),
+                Event::Empty(empty) => {
+                    let element = Self::new(empty.local_name().as_ref());
+
+                    if let Some(parent) = stack.last_mut() {
+                        parent.children.push(element);
+                    }
+                }
+                Event::End(_) => {
+                    let element = stack.pop().ok_or_else(|| invalid("start tag"))?;
+                    let parent = stack.last_mut().ok_or_else(|| invalid("start tag"))?;
+
+                    parent.children.push(element);
+                }
+                Event::Text(text) => {
+                    if let Some(element) = stack.last_mut() {
+                        element.text.push_str(&text.unescape().map_err(error)?);
+                    }
+                }
+                Event::CData(data) => {
+                    if let Some(element) = stack.last_mut() {
+                        element.text.push_str(&String::from_utf8_lossy(&data));
+                    }
+                }
+                Event::Eof => break,
+                _ => (),
+            }
+        }
+
+        match <[_; 1]>::try_from(stack) {
+            Ok([document]) => Ok(document),
+            Err(_) => Err(invalid("end tag")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn to_string() -> crate::Result {
+        let vevent = crate::VEvent::try_from(
+            "BEGIN:VEVENT\r
+UID:4088E990AD89CB3DBB484909\r
+DTSTAMP:20080205T191224Z\r
+DTSTART;TZID=US/Eastern:20081006T090000\r
+GEO:37.386013;-122.082932\r
+RRULE:FREQ=WEEKLY;COUNT=5;BYDAY=MO,WE\r
+RDATE;VALUE=PERIOD:20081010T090000/PT1H\r
+ATTENDEE;RSVP=TRUE;MEMBER=\"mailto:a@example.com\":mailto:jdoe@example.com\r
+REQUEST-STATUS:2.0;Success\r
+END:VEVENT\r
+",
+        )?;
+
+        let xcal = super::to_string(&vevent)?;
+
+        similar_asserts::assert_eq!(
+            xcal,
+            r#"<?xml version="1.0" encoding="utf-8"?><icalendar xmlns="urn:ietf:params:xml:ns:icalendar-2.0"><vevent><properties><dtstamp><date-time>2008-02-05T19:12:24Z</date-time></dtstamp><uid><text>4088E990AD89CB3DBB484909</text></uid><dtstart><parameters><tzid><text>US/Eastern</text></tzid></parameters><date-time>2008-10-06T09:00:00</date-time></dtstart><geo><latitude>37.386013</latitude><longitude>-122.08293</longitude></geo><rrule><recur><freq>WEEKLY</freq><count>5</count><interval>1</interval><byday>MO</byday><byday>WE</byday></recur></rrule><attendee><parameters><member><cal-address>mailto:a@example.com</cal-address></member><rsvp><boolean>true</boolean></rsvp></parameters><cal-address>mailto:jdoe@example.com</cal-address></attendee><rdate><period><start>2008-10-10T09:00:00</start><duration>PT1H</duration></period></rdate><request-status><code>2.0</code><description>Success</description></request-status></properties><components></components></vevent></icalendar>"#
+        );
+
+        assert_eq!(super::from_str::<crate::VEvent>(&xcal)?, vevent);
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_str() -> crate::Result {
+        let xcal = r#"<?xml version="1.0" encoding="utf-8"?>
+<icalendar xmlns="urn:ietf:params:xml:ns:icalendar-2.0">
+  <vcalendar>
+    <properties>
+      <prodid><text>-//Example Inc.//Example Calendar//EN</text></prodid>
+      <version><text>2.0</text></version>
+    </properties>
+    <components>
+      <vevent>
+        <properties>
+          <dtstamp><date-time>2008-02-05T19:12:24Z</date-time></dtstamp>
+          <dtstart><date>2008-10-06</date></dtstart>
+          <summary><text>Planning meeting &amp; lunch, room 2</text></summary>
+          <uid><text>4088E990AD89CB3DBB484909</text></uid>
+          <categories><text>MEETING</text><text>PLANNING</text></categories>
+          <rrule>
+            <recur>
+              <freq>YEARLY</freq>
+              <until>2020-01-01</until>
+              <bymonth>4</bymonth>
+            </recur>
+          </rrule>
+        </properties>
+      </vevent>
+    </components>
+  </vcalendar>
+</icalendar>
+"#;
+
+        let vcalendar = super::from_str::<crate::VCalendar>(xcal)?;
+        let vevent = &vcalendar.events[0];
+
+        assert_eq!(vevent.dtstart, "20081006".parse()?);
+        assert_eq!(
+            vevent.summary.as_ref().map(AsRef::as_ref),
+            Some("Planning meeting & lunch, room 2")
+        );
+        assert_eq!(vevent.categories.len(), 2);
+        assert_eq!(
+            vevent.rrule,
+            Some("FREQ=YEARLY;UNTIL=20200101;BYMONTH=4".parse()?)
+        );
+
+        assert!(super::from_str::<crate::VCalendar>("<icalendar>").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip() -> crate::Result {
+        for (file, vcalendar) in crate::test::calendars() {
+            let xcal = super::to_string(&vcalendar)?;
+            let round_trip = super::from_str::<crate::VCalendar>(&xcal)?;
+
+            similar_asserts::assert_eq!(
+                crate::ser::ical(&round_trip)?,
+                crate::ser::ical(&vcalendar)?,
+                "{file:?}"
+            );
+        }
+
+        Ok(())
+    }
+}